@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 
-use sitrep::test_utils::{make_hierarchy, make_stand_alone};
+use sitrep::test_utils::{make_hierarchy, make_stand_alone, make_stand_alone_untracked};
 
 const THREAD_STEPS: [usize; 11] = [1, 10, 20, 30, 40, 50, 60, 70, 80, 90, 100];
 const ITERATIONS: usize = 10_000;
@@ -84,5 +84,36 @@ pub fn hierarchical(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, stand_alone, hierarchical,);
+pub fn generation_tracking(c: &mut Criterion) {
+    let mut group = c.benchmark_group("update(): generation tracking");
+    group.throughput(Throughput::Elements(ITERATIONS as u64));
+
+    group.bench_function("tracked", |b| {
+        let (progress, _) = make_stand_alone(None);
+
+        b.iter(|| {
+            for _ in 0..ITERATIONS {
+                progress.update(|_| ());
+            }
+        });
+
+        drop(progress);
+    });
+
+    group.bench_function("untracked", |b| {
+        let (progress, _) = make_stand_alone_untracked(None);
+
+        b.iter(|| {
+            for _ in 0..ITERATIONS {
+                progress.update(|_| ());
+            }
+        });
+
+        drop(progress);
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, stand_alone, hierarchical, generation_tracking,);
 criterion_main!(benches);
@@ -58,7 +58,7 @@ fn main() {
             };
 
             // Cancel the task from the controller end of things:
-            controller.cancel();
+            controller.cancel().ok();
         }
     });
 
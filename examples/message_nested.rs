@@ -45,6 +45,7 @@ fn main() {
                 id,
                 message,
                 priority,
+                ..
             }) = event
             else {
                 // For the sake of brevity we'll only handle the message events here:
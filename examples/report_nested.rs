@@ -51,7 +51,7 @@ fn main() {
             println!(
                 "Progress updated: {fraction}% {label}",
                 fraction = 100.0 * report.fraction,
-                label = report.label.map_or(String::new(), |cow| (*cow).to_owned())
+                label = report.label.as_deref().unwrap_or_default()
             );
         }
     });
@@ -0,0 +1,52 @@
+use std::{sync::Arc, time::Duration};
+
+use sitrep::{Event, Progress, Reporter, Task, TokioMpscObserver, UpdateEvent};
+
+#[tokio::main]
+async fn main() {
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    let observer = Arc::new(TokioMpscObserver::from(sender));
+
+    let (progress, reporter) = Progress::new(Task::default(), observer);
+
+    // The sending end of the progress report:
+    let worker_handle = tokio::spawn(async move {
+        progress.set_label(Some("Crunching numbers ...".into()));
+
+        let total = 100;
+        progress.set_total(total);
+
+        for completed in 1..=total {
+            tokio::time::sleep(Duration::from_millis(25)).await;
+
+            progress.set_completed(completed);
+        }
+    });
+
+    // The receiving end of the progress report:
+    let reporter_handle = tokio::spawn(async move {
+        while let Some(event) = receiver.recv().await {
+            let Event::Update(UpdateEvent { id: _ }) = event else {
+                // For the sake of brevity we'll only handle the update events here:
+                continue;
+            };
+
+            // The reporter is only available as long as
+            // the corresponding progress is alive, too:
+            let Some(reporter) = reporter.upgrade() else {
+                break;
+            };
+
+            let report = reporter.report();
+
+            println!(
+                "Progress updated: {fraction}% {label}",
+                fraction = 100.0 * report.fraction,
+                label = report.label.as_deref().unwrap_or_default()
+            );
+        }
+    });
+
+    worker_handle.await.unwrap();
+    reporter_handle.await.unwrap();
+}
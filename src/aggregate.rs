@@ -0,0 +1,208 @@
+//! Accumulative units of work.
+
+/// The accumulative units of work of a progress (i.e. including its sub-progresses').
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub(crate) struct Aggregate {
+    /// The number of completed integer units.
+    pub(crate) completed: usize,
+    /// The number of total integer units.
+    pub(crate) total: usize,
+    /// The number of completed and total weighted units,
+    /// or `None` if none of the accumulated progresses uses weighted units.
+    pub(crate) weighted: Option<(f64, f64)>,
+    /// The fractional representation of the accumulative progress,
+    /// taking the sub-progresses' relative weights into account.
+    pub(crate) fraction: f64,
+}
+
+impl Aggregate {
+    /// Creates an aggregate of a single task's units,
+    /// with a fraction based on its units alone.
+    pub(crate) fn new(completed: usize, total: usize, weighted: Option<(f64, f64)>) -> Self {
+        let fraction = match weighted {
+            Some((completed, total)) => Self::weighted_fraction(completed, total),
+            None => Self::discrete_fraction(completed, total),
+        };
+
+        Self {
+            completed,
+            total,
+            weighted,
+            fraction,
+        }
+    }
+
+    /// Returns the integer units, with `completed` clamped to `total`
+    /// (unless there are no total units at all).
+    pub(crate) fn clamped_discrete(&self) -> (usize, usize) {
+        if self.total == 0 {
+            return (self.completed, 0);
+        }
+
+        (self.completed.min(self.total), self.total)
+    }
+
+    /// Returns the weighted units, falling back to the integer units.
+    fn units(&self) -> (f64, f64) {
+        self.weighted
+            .unwrap_or((self.completed as f64, self.total as f64))
+    }
+
+    pub(crate) fn is_indeterminate(&self) -> bool {
+        let is_discrete_indeterminate = (self.completed == 0) && (self.total == 0);
+        let is_weighted_indeterminate = self.weighted.map_or(true, |(completed, total)| {
+            (completed == 0.0) && (total == 0.0)
+        });
+
+        is_discrete_indeterminate && is_weighted_indeterminate
+    }
+
+    pub(crate) fn discrete_fraction(completed: usize, total: usize) -> f64 {
+        match (completed, total) {
+            (0, 0) => 0.0,
+            (_, 0) => 1.0,
+            (completed, total) => 1.0 * (completed as f64) / (total as f64),
+        }
+    }
+
+    fn weighted_fraction(completed: f64, total: f64) -> f64 {
+        if total > 0.0 {
+            (completed / total).clamp(0.0, 1.0)
+        } else if completed > 0.0 {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Accumulates a progress' own aggregate with the ones of its sub-progresses.
+///
+/// The unit counts simply get summed up (with integer units counting as weighted units,
+/// as soon as any of the accumulated aggregates uses weighted units).
+///
+/// As long as all sub-progresses have the default weight of `1.0` the fraction is
+/// proportional to the accumulated (total) units. Otherwise the fraction is the weighted
+/// mean of the determinate sub-progresses' fractions, with the progress' own task
+/// (if determinate) having a weight of `1.0`.
+#[derive(Clone, Debug)]
+pub(crate) struct Accumulator {
+    completed: usize,
+    total: usize,
+    is_weighted: bool,
+    units: (f64, f64),
+    /// The sum of fractions scaled by their total units, and the sum of total units.
+    proportional: (f64, f64),
+    /// The sum of fractions scaled by their weights, and the sum of weights.
+    weighted_mean: (f64, f64),
+    has_weights: bool,
+}
+
+impl Accumulator {
+    pub(crate) fn new(own: Aggregate) -> Self {
+        let mut accumulator = Self {
+            completed: 0,
+            total: 0,
+            is_weighted: false,
+            units: (0.0, 0.0),
+            proportional: (0.0, 0.0),
+            weighted_mean: (0.0, 0.0),
+            has_weights: false,
+        };
+
+        accumulator.add(own, 1.0);
+        accumulator.has_weights = false;
+
+        accumulator
+    }
+
+    /// Adds a sub-progress' `aggregate` with its relative `weight`.
+    pub(crate) fn add(&mut self, aggregate: Aggregate, weight: f64) {
+        self.completed = self.completed.saturating_add(aggregate.completed);
+        self.total = self.total.saturating_add(aggregate.total);
+
+        self.is_weighted |= aggregate.weighted.is_some();
+
+        let (completed, total) = aggregate.units();
+        self.units.0 += completed;
+        self.units.1 += total;
+
+        self.proportional.0 += aggregate.fraction * total;
+        self.proportional.1 += total;
+
+        if !aggregate.is_indeterminate() {
+            self.weighted_mean.0 += aggregate.fraction * weight;
+            self.weighted_mean.1 += weight;
+        }
+
+        self.has_weights |= weight != 1.0;
+    }
+
+    pub(crate) fn finish(self) -> Aggregate {
+        let weighted = self.is_weighted.then_some(self.units);
+        let aggregate = Aggregate::new(self.completed, self.total, weighted);
+
+        let fraction = if self.has_weights && self.weighted_mean.1 > 0.0 {
+            self.weighted_mean.0 / self.weighted_mean.1
+        } else if self.proportional.1 > 0.0 {
+            self.proportional.0 / self.proportional.1
+        } else {
+            aggregate.fraction
+        };
+
+        Aggregate {
+            fraction: fraction.clamp(0.0, 1.0),
+            ..aggregate
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn discrete(completed: usize, total: usize) -> Aggregate {
+        Aggregate::new(completed, total, None)
+    }
+
+    #[test]
+    fn proportional() {
+        let mut accumulator = Accumulator::new(discrete(0, 0));
+        accumulator.add(discrete(1, 2), 1.0);
+        accumulator.add(discrete(0, 6), 1.0);
+
+        let aggregate = accumulator.finish();
+
+        assert_eq!(aggregate.clamped_discrete(), (1, 8));
+        assert_eq!(aggregate.weighted, None);
+        assert_eq!(aggregate.fraction, 0.125);
+    }
+
+    #[test]
+    fn weighted_mean() {
+        let mut accumulator = Accumulator::new(discrete(0, 0));
+        accumulator.add(discrete(1, 2), 3.0);
+        accumulator.add(discrete(0, 100), 1.0);
+        // Indeterminate sub-progresses don't hold back the fraction:
+        accumulator.add(discrete(0, 0), 5.0);
+
+        let aggregate = accumulator.finish();
+
+        // The unit counts remain unscaled:
+        assert_eq!(aggregate.clamped_discrete(), (1, 102));
+        assert_eq!(aggregate.weighted, None);
+        // (3.0 * 0.5 + 1.0 * 0.0) / (3.0 + 1.0):
+        assert_eq!(aggregate.fraction, 0.375);
+    }
+
+    #[test]
+    fn mixed_units() {
+        let mut accumulator = Accumulator::new(discrete(1, 10));
+        accumulator.add(Aggregate::new(0, 0, Some((2.5, 5.0))), 1.0);
+
+        let aggregate = accumulator.finish();
+
+        assert_eq!(aggregate.weighted, Some((3.5, 15.0)));
+        assert_eq!(aggregate.fraction, 3.5 / 15.0);
+    }
+}
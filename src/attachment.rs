@@ -0,0 +1,92 @@
+//! A child's attachment to its parent.
+
+use std::sync::{Arc, Weak};
+
+use crate::{Observer, Progress};
+
+/// A guard representing a child's attachment to its parent.
+///
+/// Created by [`Progress::attach_child_scoped()`](Progress::attach_child_scoped).
+///
+/// The guard holds on to the child's original `Observer`,
+/// which gets restored when detaching the child via the guard.
+///
+/// By default dropping the guard leaves the child attached.
+#[must_use = "dropping the guard without calling `detach()` leaves the child attached"]
+pub struct ChildAttachment {
+    parent: Weak<Progress>,
+    child: Arc<Progress>,
+    observer: Option<Arc<dyn Observer>>,
+    detach_on_drop: bool,
+}
+
+impl ChildAttachment {
+    pub(crate) fn new(
+        parent: &Arc<Progress>,
+        child: &Arc<Progress>,
+        observer: Arc<dyn Observer>,
+    ) -> Self {
+        Self {
+            parent: Arc::downgrade(parent),
+            child: Arc::clone(child),
+            observer: Some(observer),
+            detach_on_drop: false,
+        }
+    }
+
+    /// Builder-style method for making the guard detach the child when dropped.
+    ///
+    /// The default is `false` (i.e. the child stays attached).
+    pub fn detach_on_drop(mut self, detach_on_drop: bool) -> Self {
+        self.detach_on_drop = detach_on_drop;
+        self
+    }
+
+    /// Returns the attached child.
+    pub fn child(&self) -> &Arc<Progress> {
+        &self.child
+    }
+
+    /// Detaches the child from its parent, restoring its original `Observer`.
+    ///
+    /// Does nothing if the child has already been detached by other means.
+    pub fn detach(mut self) {
+        self.detach_impl();
+    }
+
+    fn detach_impl(&mut self) {
+        let Some(observer) = self.observer.take() else {
+            return;
+        };
+
+        let Some(parent) = self.parent.upgrade() else {
+            return;
+        };
+
+        if parent.child(self.child.id()).is_none() {
+            return;
+        }
+
+        parent.detach_child(&self.child, observer);
+    }
+}
+
+impl Drop for ChildAttachment {
+    fn drop(&mut self) {
+        if self.detach_on_drop {
+            self.detach_impl();
+        }
+    }
+}
+
+impl std::fmt::Debug for ChildAttachment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parent = self.parent.upgrade().map(|parent| parent.id());
+
+        f.debug_struct("ChildAttachment")
+            .field("parent", &parent)
+            .field("child", &self.child.id())
+            .field("detach_on_drop", &self.detach_on_drop)
+            .finish()
+    }
+}
@@ -0,0 +1,159 @@
+//! A progress builder.
+
+use std::sync::{Arc, Weak};
+
+use crate::{Clock, Controller, Observer, Progress, ProgressId, Reporter, Task};
+
+/// The options a progress gets created with.
+///
+/// Options that are `None` get inherited from the parent (if any), or defaulted.
+#[derive(Clone, Default)]
+pub(crate) struct ProgressOptions {
+    pub(crate) message_buffer: Option<usize>,
+    pub(crate) on_root_drop: Option<OnRootDrop>,
+    pub(crate) track_generations: Option<bool>,
+    pub(crate) clock: Option<Arc<dyn Clock>>,
+    pub(crate) rate_smoothing: Option<f64>,
+}
+
+impl std::fmt::Debug for ProgressOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProgressOptions")
+            .field("message_buffer", &self.message_buffer)
+            .field("on_root_drop", &self.on_root_drop)
+            .field("track_generations", &self.track_generations)
+            .field("clock", &self.clock.as_ref().map(|_| ".."))
+            .field("rate_smoothing", &self.rate_smoothing)
+            .finish()
+    }
+}
+
+/// The policy for what happens to a progress tree's surviving
+/// sub-progresses when its root progress gets dropped.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub enum OnRootDrop {
+    /// Surviving sub-progresses keep running, detached from their former tree.
+    #[default]
+    KeepRunning,
+    /// Surviving sub-progresses get canceled (regardless of their `is_cancelable`),
+    /// signalling their workers that their results are no longer of interest.
+    CancelSubtree,
+}
+
+/// A builder for creating progress objects with non-default options.
+///
+/// Options that are not explicitly provided get inherited from the parent progress
+/// (when building via `build_with_parent()`), or defaulted otherwise.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use sitrep::{NopObserver, ProgressBuilder, Task};
+/// let (progress, _reporter) = ProgressBuilder::new(Task::default())
+///     .message_buffer(10)
+///     .build(Arc::new(NopObserver));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ProgressBuilder {
+    task: Task,
+    options: ProgressOptions,
+}
+
+impl ProgressBuilder {
+    /// Creates a builder for a progress object for the given `task`.
+    pub fn new(task: Task) -> Self {
+        Self {
+            task,
+            options: ProgressOptions::default(),
+        }
+    }
+
+    /// Builder-style method for setting the capacity of the progress' message buffer,
+    /// which retains the most recently emitted messages for later retrieval
+    /// via [`Progress::recent_messages()`], or replay via [`Progress::replay_messages()`].
+    ///
+    /// The default capacity is `0` (i.e. no messages are being retained),
+    /// or the parent's capacity, if there is a parent.
+    ///
+    /// # Memory
+    ///
+    /// Each progress retains up to `capacity` of its own messages,
+    /// so a tree of `n` progresses that all inherited the same capacity
+    /// may end up retaining up to `n * capacity` messages in total.
+    pub fn message_buffer(mut self, capacity: usize) -> Self {
+        self.options.message_buffer = Some(capacity);
+        self
+    }
+
+    /// Builder-style method for setting the progress' drop policy,
+    /// which gets applied when the progress gets dropped as the root of its tree.
+    ///
+    /// The default policy is `OnRootDrop::KeepRunning`.
+    ///
+    /// Unlike other options the drop policy does not get inherited from the parent.
+    pub fn on_root_drop(mut self, on_root_drop: OnRootDrop) -> Self {
+        self.options.on_root_drop = Some(on_root_drop);
+        self
+    }
+
+    /// Builder-style method for enabling/disabling the tracking of generations,
+    /// i.e. the bookkeeping of when a progress was most recently changed.
+    ///
+    /// The default is `true`, or the parent's setting, if there is a parent.
+    ///
+    /// Disabling generation tracking reduces the cost of each update, which can be
+    /// worthwhile for simple use cases that only ever generate full reports.
+    ///
+    /// Generation tracking is required for change detection, hence with generation
+    /// tracking disabled [`Reporter::partial_report()`] returns full reports.
+    pub fn track_generations(mut self, track_generations: bool) -> Self {
+        self.options.track_generations = Some(track_generations);
+        self
+    }
+
+    /// Builder-style method for setting the clock used for time measurements
+    /// (e.g. for [`Report::eta()`](crate::Report::eta)).
+    ///
+    /// The default clock is `SystemClock`, or the parent's clock, if there is a parent.
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.options.clock = Some(clock);
+        self
+    }
+
+    /// Builder-style method for setting the smoothing factor (within `0.0..=1.0`)
+    /// of the exponentially-weighted moving average used for estimating
+    /// the progress' completion rate (see [`Report::units_per_second()`](crate::Report::units_per_second)).
+    ///
+    /// Higher values favor recent samples, making the estimate more responsive,
+    /// while lower values make it more stable.
+    ///
+    /// The default smoothing factor is `0.3`, or the parent's factor, if there is a parent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `smoothing` is not within `0.0..=1.0`.
+    pub fn rate_smoothing(mut self, smoothing: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&smoothing),
+            "smoothing factor must be within 0.0..=1.0"
+        );
+        self.options.rate_smoothing = Some(smoothing);
+        self
+    }
+
+    /// Builds the progress object, emitting relevant events to `observer`.
+    ///
+    /// See [`Progress::new()`] for more info.
+    pub fn build(
+        self,
+        observer: Arc<dyn Observer>,
+    ) -> (Arc<Progress>, Weak<impl Reporter + Controller>) {
+        Progress::new_root_impl(ProgressId::new_unique(), self.task, observer, self.options)
+    }
+
+    /// Builds the progress object as a sub-progress of `parent`.
+    ///
+    /// See [`Progress::new_with_parent()`] for more info.
+    pub fn build_with_parent(self, parent: &Arc<Progress>) -> Arc<Progress> {
+        Progress::new_child_impl(ProgressId::new_unique(), self.task, parent, self.options)
+    }
+}
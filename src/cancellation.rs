@@ -0,0 +1,78 @@
+//! Cancellation tokens for progresses.
+
+use std::{sync::Arc, time::Duration};
+
+use parking_lot::{Condvar, Mutex};
+
+#[derive(Default, Debug)]
+struct Signal {
+    is_canceled: Mutex<bool>,
+    condvar: Condvar,
+}
+
+/// A cheaply clonable handle for observing the cancellation of a progress' task,
+/// without having to poll its state.
+///
+/// Created by [`Progress::cancellation_token()`](crate::Progress::cancellation_token).
+///
+/// The token gets signaled once the associated task transitions to `State::Canceled`
+/// and remains signaled from then on, even if the task leaves its canceled state
+/// again (e.g. by getting reset), in which case the progress hands out fresh tokens.
+#[derive(Clone, Default, Debug)]
+pub struct CancellationToken {
+    signal: Arc<Signal>,
+}
+
+impl CancellationToken {
+    /// Returns `true` if the associated task has been canceled, otherwise `false`.
+    pub fn is_canceled(&self) -> bool {
+        *self.signal.is_canceled.lock()
+    }
+
+    /// Blocks the current thread until the associated task has been canceled.
+    pub fn wait(&self) {
+        let mut is_canceled = self.signal.is_canceled.lock();
+
+        while !*is_canceled {
+            self.signal.condvar.wait(&mut is_canceled);
+        }
+    }
+
+    /// Blocks the current thread until the associated task has been canceled,
+    /// or `timeout` has elapsed, returning `true` if it has been canceled, otherwise `false`.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let mut is_canceled = self.signal.is_canceled.lock();
+
+        if !*is_canceled {
+            let _ = self.signal.condvar.wait_while_for(
+                &mut is_canceled,
+                |is_canceled| !*is_canceled,
+                timeout,
+            );
+        }
+
+        *is_canceled
+    }
+
+    pub(crate) fn cancel(&self) {
+        *self.signal.is_canceled.lock() = true;
+        self.signal.condvar.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wait_timeout() {
+        let token = CancellationToken::default();
+
+        assert!(!token.wait_timeout(Duration::from_millis(1)));
+
+        token.cancel();
+
+        assert!(token.wait_timeout(Duration::from_millis(1)));
+        assert!(token.clone().is_canceled());
+    }
+}
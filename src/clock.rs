@@ -0,0 +1,56 @@
+//! Clocks for measuring time.
+
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+/// Types for obtaining the current time.
+pub trait Clock: Send + Sync {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+}
+
+/// Implementation of `Clock` based on `std::time::Instant::now()`.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Implementation of `Clock` that only advances when explicitly told to,
+/// allowing for deterministic tests of time-dependent behavior.
+#[derive(Debug)]
+pub struct MockClock {
+    origin: Instant,
+    elapsed: Mutex<Duration>,
+}
+
+impl MockClock {
+    /// Creates a clock that is frozen at the current instant.
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+            elapsed: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Advances the clock by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.elapsed.lock() += duration;
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.origin + *self.elapsed.lock()
+    }
+}
@@ -0,0 +1,45 @@
+//! Errors.
+
+/// An error returned by a `Controller` for an unsupported operation.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ControlError {
+    /// The task is not pausable (or resumable).
+    NotPausable,
+    /// The task is not cancelable.
+    NotCancelable,
+    /// The task does not exist within the tree.
+    NotFound,
+}
+
+impl std::fmt::Display for ControlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotPausable => write!(f, "not pausable"),
+            Self::NotCancelable => write!(f, "not cancelable"),
+            Self::NotFound => write!(f, "not found"),
+        }
+    }
+}
+
+impl std::error::Error for ControlError {}
+
+/// An error returned when parsing an unrecognized `PriorityLevel`.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ParsePriorityLevelError {
+    pub(crate) unknown: String,
+}
+
+impl ParsePriorityLevelError {
+    /// Returns the unrecognized value.
+    pub fn unknown(&self) -> &str {
+        &self.unknown
+    }
+}
+
+impl std::fmt::Display for ParsePriorityLevelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unrecognized priority level: {:?}", self.unknown)
+    }
+}
+
+impl std::error::Error for ParsePriorityLevelError {}
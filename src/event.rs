@@ -2,23 +2,35 @@
 
 use std::borrow::Cow;
 
-use crate::{PriorityLevel, ProgressId};
+use crate::{PriorityLevel, ProgressId, State};
 
 /// A progress event.
 #[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A progress had its task updated.
     Update(UpdateEvent),
     /// A progress has posted a message.
     Message(MessageEvent),
-    /// A progress has been removed.
+    /// A progress has been attached to a parent.
+    ///
+    /// Emitted for progresses created as sub-progresses,
+    /// as well as for progresses that get attached to a parent.
+    Attachment(AttachmentEvent),
+    /// A progress has been detached from its parent.
+    ///
+    /// Only emitted for progresses that get detached from a parent,
+    /// not for progresses that simply get dropped.
     Detachment(DetachmentEvent),
+    /// A progress had its task's state changed.
+    StateChange(StateChangeEvent),
     /// The generation counter has overflown.
     GenerationOverflow,
 }
 
 /// A update event.
 #[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateEvent {
     /// The associated progress' identifier.
     pub id: ProgressId,
@@ -26,6 +38,7 @@ pub struct UpdateEvent {
 
 /// A message event.
 #[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MessageEvent {
     /// The associated progress' identifier.
     pub id: ProgressId,
@@ -33,11 +46,126 @@ pub struct MessageEvent {
     pub message: Cow<'static, str>,
     /// The message's priority level.
     pub priority: PriorityLevel,
+    /// The message's structured key-value fields, if any.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub fields: Vec<(Cow<'static, str>, Cow<'static, str>)>,
 }
 
-/// A update event.
+/// An attachment event.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttachmentEvent {
+    /// The associated progress' identifier.
+    pub id: ProgressId,
+    /// The identifier of the progress' new parent.
+    pub parent_id: ProgressId,
+}
+
+/// A detachment event.
 #[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DetachmentEvent {
     /// The associated progress' identifier.
     pub id: ProgressId,
 }
+
+/// A state change event.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateChangeEvent {
+    /// The associated progress' identifier.
+    pub id: ProgressId,
+    /// The task's state before the change.
+    pub old: State,
+    /// The task's state after the change.
+    pub new: State,
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    mod serialization {
+        use std::borrow::Cow;
+
+        use crate::{
+            AttachmentEvent, DetachmentEvent, Event, MessageEvent, PriorityLevel, ProgressId,
+            State, StateChangeEvent, UpdateEvent,
+        };
+
+        fn round_trip(event: Event) -> Event {
+            let json = serde_json::to_string(&event).unwrap();
+            serde_json::from_str(&json).unwrap()
+        }
+
+        #[test]
+        fn update() {
+            let event = Event::Update(UpdateEvent {
+                id: ProgressId::new_unique(),
+            });
+
+            assert_eq!(round_trip(event.clone()), event);
+        }
+
+        #[test]
+        fn message() {
+            let event = Event::Message(MessageEvent {
+                id: ProgressId::new_unique(),
+                message: "message".into(),
+                priority: PriorityLevel::Warn,
+                fields: vec![("key".into(), "value".into())],
+            });
+
+            let json = serde_json::to_string(&event).unwrap();
+
+            assert!(json.contains(r#""priority":"warn""#), "{json}");
+
+            let Event::Message(deserialized) = round_trip(event.clone()) else {
+                panic!("expected message event");
+            };
+
+            assert!(matches!(deserialized.message, Cow::Owned(_)));
+            assert_eq!(Event::Message(deserialized), event);
+        }
+
+        #[test]
+        fn attachment() {
+            let event = Event::Attachment(AttachmentEvent {
+                id: ProgressId::new_unique(),
+                parent_id: ProgressId::new_unique(),
+            });
+
+            assert_eq!(round_trip(event.clone()), event);
+        }
+
+        #[test]
+        fn detachment() {
+            let event = Event::Detachment(DetachmentEvent {
+                id: ProgressId::new_unique(),
+            });
+
+            assert_eq!(round_trip(event.clone()), event);
+        }
+
+        #[test]
+        fn state_change() {
+            let event = Event::StateChange(StateChangeEvent {
+                id: ProgressId::new_unique(),
+                old: State::Running,
+                new: State::Canceled,
+            });
+
+            assert_eq!(round_trip(event.clone()), event);
+        }
+
+        #[test]
+        fn generation_overflow() {
+            assert_eq!(
+                round_trip(Event::GenerationOverflow),
+                Event::GenerationOverflow
+            );
+        }
+    }
+}
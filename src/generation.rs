@@ -4,6 +4,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 ///
 /// Specifies the generation at which a value was last changed.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Generation(pub(crate) usize);
 
 impl Generation {
@@ -13,11 +14,33 @@ impl Generation {
     /// Returns the largest possible generation.
     pub const MAX: Self = Self(usize::MAX);
 
+    /// Creates a generation from a raw internal generational counter value,
+    /// as previously obtained via [`Generation::as_raw()`].
+    pub fn from_raw(raw: usize) -> Self {
+        Self(raw)
+    }
+
     /// Returns the raw internal generational counter value.
     pub fn as_raw(&self) -> usize {
         self.0
     }
 
+    /// Returns `true` if `self` is newer than `other`, accounting for
+    /// the generation counter having wrapped around (at most once) in between,
+    /// otherwise `false`.
+    ///
+    /// Much like TCP sequence number comparison this assumes that the two generations
+    /// are never more than half of the `usize` range apart, i.e. that consumers observe
+    /// changes frequently enough for the counter not to advance by more than
+    /// `usize::MAX / 2` between observations. Beyond that, the result is meaningless.
+    ///
+    /// Prefer this over `>` for change detection across `Event::GenerationOverflow`.
+    pub fn is_newer_than(&self, other: Generation) -> bool {
+        let distance = self.0.wrapping_sub(other.0);
+
+        distance != 0 && distance <= usize::MAX / 2
+    }
+
     pub(crate) fn add(self, increment: usize) -> (Self, bool) {
         let (value, overflow) = self.0.overflowing_add(increment);
         (Self(value), overflow)
@@ -49,3 +72,64 @@ impl AtomicGeneration {
         Generation(self.0.fetch_add(increment, order))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_round_trip() {
+        for raw in [usize::MIN, 1, 42, usize::MAX] {
+            assert_eq!(Generation::from_raw(raw).as_raw(), raw);
+        }
+
+        assert_eq!(Generation::from_raw(usize::MIN), Generation::MIN);
+        assert_eq!(Generation::from_raw(usize::MAX), Generation::MAX);
+    }
+
+    #[test]
+    fn ordering() {
+        let older = Generation::from_raw(Generation(41).as_raw());
+        let newer = Generation::from_raw(Generation(42).as_raw());
+
+        assert!(older < newer);
+        assert!(newer > older);
+        assert_eq!(older.max(newer), newer);
+        assert_eq!(newer, Generation(42));
+    }
+
+    mod is_newer_than {
+        use super::*;
+
+        #[test]
+        fn without_wrap() {
+            assert!(Generation(2).is_newer_than(Generation(1)));
+            assert!(!Generation(1).is_newer_than(Generation(2)));
+            assert!(!Generation(1).is_newer_than(Generation(1)));
+        }
+
+        #[test]
+        fn across_wrap() {
+            let (wrapped, overflow) = Generation::MAX.add(3);
+
+            assert!(overflow);
+            assert_eq!(wrapped, Generation(2));
+
+            assert!(wrapped.is_newer_than(Generation::MAX));
+            assert!(wrapped.is_newer_than(Generation(usize::MAX - 10)));
+            assert!(!Generation::MAX.is_newer_than(wrapped));
+
+            // Naive comparison gets this wrong:
+            assert!(wrapped < Generation::MAX);
+        }
+
+        #[test]
+        fn half_range() {
+            let half = usize::MAX / 2;
+
+            assert!(Generation(half).is_newer_than(Generation::MIN));
+            assert!(!Generation(half + 1).is_newer_than(Generation::MIN));
+            assert!(Generation::MIN.is_newer_than(Generation(half + 2)));
+        }
+    }
+}
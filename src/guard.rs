@@ -0,0 +1,76 @@
+//! RAII guards for progresses.
+
+use std::sync::Weak;
+
+use crate::{PriorityLevel, Progress};
+
+/// A guard representing an outstanding unit of work.
+///
+/// Created by [`Progress::unit_guard()`](Progress::unit_guard).
+///
+/// Dropping the guard increments the associated task's completed unit count by `1`
+/// (including when dropped due to an early return, or unwinding),
+/// unless the guard got [forgotten](CompletionGuard::forget),
+/// or the task has been canceled since the guard's creation,
+/// in which case the work is considered abandoned.
+///
+/// The guard does not keep the associated progress alive.
+#[must_use = "dropping the guard immediately completes its unit of work"]
+#[derive(Debug)]
+pub struct CompletionGuard {
+    progress: Weak<Progress>,
+    cancellations: usize,
+}
+
+impl CompletionGuard {
+    pub(crate) fn new(progress: Weak<Progress>, cancellations: usize) -> Self {
+        Self {
+            progress,
+            cancellations,
+        }
+    }
+
+    /// Consumes the guard without incrementing the task's completed unit count,
+    /// i.e. abandoning its unit of work.
+    pub fn forget(mut self) {
+        self.progress = Weak::new();
+    }
+}
+
+impl Drop for CompletionGuard {
+    fn drop(&mut self) {
+        let Some(progress) = self.progress.upgrade() else {
+            return;
+        };
+
+        if progress.was_canceled_since(self.cancellations) {
+            return;
+        }
+
+        progress.increment_completed();
+    }
+}
+
+/// A guard temporarily overriding a progress' minimum priority level.
+///
+/// Created by [`Progress::with_min_priority_level()`](Progress::with_min_priority_level).
+///
+/// Dropping the guard restores the progress' previous override (if any).
+#[must_use = "dropping the guard immediately restores the previous minimum priority level"]
+#[derive(Debug)]
+pub struct PriorityGuard<'a> {
+    progress: &'a Progress,
+    previous: Option<PriorityLevel>,
+}
+
+impl<'a> PriorityGuard<'a> {
+    pub(crate) fn new(progress: &'a Progress, previous: Option<PriorityLevel>) -> Self {
+        Self { progress, previous }
+    }
+}
+
+impl Drop for PriorityGuard<'_> {
+    fn drop(&mut self) {
+        self.progress.set_min_priority_level(self.previous);
+    }
+}
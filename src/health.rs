@@ -0,0 +1,57 @@
+//! A progress tree's health.
+
+use crate::{PriorityLevel, State};
+
+/// A summary of a progress tree's health.
+#[derive(Clone, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreeHealth {
+    /// The total number of progresses in the tree (including its root).
+    pub nodes: usize,
+    /// The number of progresses in the tree with a running task.
+    pub running: usize,
+    /// The number of progresses in the tree with a paused task.
+    pub paused: usize,
+    /// The number of progresses in the tree with a finished task.
+    pub finished: usize,
+    /// The number of progresses in the tree with a canceled task.
+    pub canceled: usize,
+    /// A fractional representation of the tree's accumulative progress
+    /// within range of `0.0..=1.0`.
+    pub fraction: f64,
+    /// The highest priority level among the messages emitted within the tree,
+    /// or `None` if none got emitted.
+    pub max_priority: Option<PriorityLevel>,
+}
+
+/// The number of progresses per task state within a (report) tree.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateCounts {
+    /// The number of progresses with a running task.
+    pub running: usize,
+    /// The number of progresses with a paused task.
+    pub paused: usize,
+    /// The number of progresses with a finished task.
+    pub finished: usize,
+    /// The number of progresses with a canceled task.
+    pub canceled: usize,
+}
+
+impl StateCounts {
+    /// Returns the total number of progresses (i.e. the sum of all counts).
+    pub fn total(&self) -> usize {
+        self.running + self.paused + self.finished + self.canceled
+    }
+
+    pub(crate) fn count(&mut self, state: State) {
+        let count = match state {
+            State::Running => &mut self.running,
+            State::Paused => &mut self.paused,
+            State::Finished => &mut self.finished,
+            State::Canceled => &mut self.canceled,
+        };
+
+        *count += 1;
+    }
+}
@@ -0,0 +1,4 @@
+//! Integrations with third-party crates.
+
+#[cfg(feature = "indicatif")]
+pub(crate) mod indicatif;
@@ -0,0 +1,108 @@
+//! An integration with the `indicatif` crate.
+
+use std::sync::Arc;
+
+use ::indicatif::{ProgressBar, ProgressStyle};
+
+use crate::{Report, Reporter};
+
+/// Generates a fresh report from `reporter` and updates `bar` accordingly,
+/// returning the report.
+///
+/// The bar's length & position reflect the report's `total` & `completed`,
+/// while its message reflects the report's `label`.
+///
+/// Indeterminate reports switch the bar to a spinner (i.e. a bar without a length,
+/// using `ProgressStyle::default_spinner()`), while determinate reports switch it
+/// back to a bar (using `ProgressStyle::default_bar()`). Custom styles thus only
+/// survive for as long as the report's determinacy doesn't change.
+///
+/// Call this on the receiving end of the observer (e.g. upon receiving an `Event::Update`),
+/// rather than from within `Observer::observe()`, as the latter gets called
+/// while the emitting progress is locked.
+pub fn drive_indicatif<R>(reporter: &Arc<R>, bar: &ProgressBar) -> Report
+where
+    R: Reporter,
+{
+    let report = reporter.report();
+
+    update_progress_bar(bar, &report);
+
+    report
+}
+
+fn update_progress_bar(bar: &ProgressBar, report: &Report) {
+    let is_spinner = bar.length().is_none();
+
+    if report.is_indeterminate {
+        if !is_spinner {
+            bar.set_style(ProgressStyle::default_spinner());
+            bar.unset_length();
+        }
+
+        bar.tick();
+    } else {
+        if is_spinner {
+            bar.set_style(ProgressStyle::default_bar());
+        }
+
+        bar.set_length(report.total as u64);
+        bar.set_position(report.completed as u64);
+    }
+
+    bar.set_message(report.label.clone().unwrap_or_default());
+}
+
+#[cfg(test)]
+mod tests {
+    use ::indicatif::ProgressDrawTarget;
+
+    use crate::{NopObserver, Progress, Task};
+
+    use super::*;
+
+    fn hidden_bar(len: Option<u64>) -> ProgressBar {
+        ProgressBar::with_draw_target(len, ProgressDrawTarget::hidden())
+    }
+
+    #[test]
+    fn determinate() {
+        let (progress, reporter) = Progress::new(
+            Task::default().label("label").total(10),
+            Arc::new(NopObserver),
+        );
+        let reporter = reporter.upgrade().unwrap();
+
+        let bar = hidden_bar(None);
+
+        progress.set_completed(3);
+
+        let report = drive_indicatif(&reporter, &bar);
+
+        assert_eq!(report.completed, 3);
+        assert_eq!(bar.length(), Some(10));
+        assert_eq!(bar.position(), 3);
+        assert_eq!(bar.message(), "label");
+    }
+
+    #[test]
+    fn indeterminate() {
+        let (progress, reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let reporter = reporter.upgrade().unwrap();
+
+        let bar = hidden_bar(Some(100));
+
+        drive_indicatif(&reporter, &bar);
+
+        assert_eq!(bar.length(), None);
+        assert_eq!(bar.message(), "");
+
+        progress.set_total(5);
+        progress.set_completed(5);
+
+        drive_indicatif(&reporter, &bar);
+
+        assert_eq!(bar.length(), Some(5));
+        assert_eq!(bar.position(), 5);
+    }
+}
@@ -0,0 +1,114 @@
+//! Iterator adapters for tracking progress.
+
+use std::sync::Arc;
+
+use crate::Progress;
+
+/// Extension trait for tracking an iterator's progress.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use sitrep::{NopObserver, Progress, ProgressIterator, Task};
+/// let (progress, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+///
+/// for item in (0..100).track(Arc::clone(&progress)) {
+///     // …
+/// }
+///
+/// assert_eq!(progress.completed(), 100);
+/// ```
+pub trait ProgressIterator: Iterator + Sized {
+    /// Returns an iterator that sets the `progress`' total unit count to `self.len()`
+    /// up front and increments its completed unit count per yielded item.
+    fn track(self, progress: Arc<Progress>) -> Tracked<Self>
+    where
+        Self: ExactSizeIterator,
+    {
+        progress.set_total(self.len());
+
+        Tracked::new(self, progress)
+    }
+
+    /// Returns an iterator that increments the `progress`' completed unit count
+    /// per yielded item, leaving its total unit count unchanged.
+    ///
+    /// Useful for iterators of unknown length, for which the progress'
+    /// total unit count either is unknown (i.e. indeterminate), or set separately.
+    fn track_indeterminate(self, progress: Arc<Progress>) -> Tracked<Self> {
+        Tracked::new(self, progress)
+    }
+}
+
+impl<I> ProgressIterator for I where I: Iterator {}
+
+/// An iterator that tracks the progress of an inner iterator.
+///
+/// This `struct` is created by the methods of [`ProgressIterator`].
+#[derive(Debug)]
+pub struct Tracked<I> {
+    iter: I,
+    progress: Arc<Progress>,
+}
+
+impl<I> Tracked<I> {
+    fn new(iter: I, progress: Arc<Progress>) -> Self {
+        Self { iter, progress }
+    }
+}
+
+impl<I> Iterator for Tracked<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+
+        self.progress.increment_completed();
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> ExactSizeIterator for Tracked<I> where I: ExactSizeIterator {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NopObserver, Reporter, Task};
+
+    use super::*;
+
+    #[test]
+    fn track() {
+        let (progress, reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        let sum: usize = (0..100).track(Arc::clone(&progress)).sum();
+
+        assert_eq!(sum, 4950);
+
+        let report = reporter.upgrade().unwrap().report();
+
+        assert_eq!(report.completed, 100);
+        assert_eq!(report.total, 100);
+    }
+
+    #[test]
+    fn track_indeterminate() {
+        let (progress, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        let count = (0..100)
+            .filter(|value| value % 2 == 0)
+            .track_indeterminate(Arc::clone(&progress))
+            .count();
+
+        assert_eq!(count, 50);
+
+        assert_eq!(progress.completed(), 50);
+        assert_eq!(progress.total(), 0);
+    }
+}
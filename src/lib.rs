@@ -2,23 +2,77 @@
 
 //! Frontend-agnostic progress reporting.
 
+mod aggregate;
+mod attachment;
+mod builder;
+mod cancellation;
+mod clock;
+mod error;
 mod event;
 mod generation;
+mod guard;
+mod health;
+#[cfg(feature = "indicatif")]
+mod integrations;
+mod iter;
+mod message_buffer;
+mod middleware;
 mod observer;
+mod path;
+#[cfg(feature = "serde")]
+mod pipe;
 mod priority;
 mod progress;
+mod rate;
 mod report;
+mod sampled;
 mod task;
 
 pub use self::{
-    event::{DetachmentEvent, Event, MessageEvent, UpdateEvent},
+    attachment::ChildAttachment,
+    builder::{OnRootDrop, ProgressBuilder},
+    cancellation::CancellationToken,
+    clock::{Clock, MockClock, SystemClock},
+    error::{ControlError, ParsePriorityLevelError},
+    event::{AttachmentEvent, DetachmentEvent, Event, MessageEvent, StateChangeEvent, UpdateEvent},
     generation::Generation,
-    observer::{NopObserver, StdMpscObserver},
+    guard::{CompletionGuard, PriorityGuard},
+    health::{StateCounts, TreeHealth},
+    iter::{ProgressIterator, Tracked},
+    middleware::{FilterMiddleware, Layered, MeteringMiddleware, ObserverMiddleware},
+    observer::{
+        CoalescingObserver, DebouncingObserver, FnObserver, MultiObserver, NopObserver,
+        PollObserver, RateLimitObserver, RemappingObserver, RingBufferObserver, StdMpscObserver,
+        TerminalObserver, WindowSummary, WindowedAggregatorObserver,
+    },
+    path::NodePath,
     priority::PriorityLevel,
-    progress::{Controller, Observer, Progress, ProgressId, Reporter},
-    report::Report,
+    progress::{combine, Controller, Observer, Progress, ProgressId, Reporter},
+    report::{aggregate_reports, Report, ReportDiff},
+    sampled::Sampled,
     task::{State, Task},
 };
 
+#[cfg(feature = "serde")]
+pub use self::{
+    observer::JsonLinesObserver,
+    pipe::{PipeObserver, PipeReader},
+};
+
+#[cfg(feature = "async-tokio")]
+pub use self::observer::TokioMpscObserver;
+
+#[cfg(feature = "crossbeam")]
+pub use self::observer::CrossbeamObserver;
+
+#[cfg(feature = "tracing")]
+pub use self::observer::TracingObserver;
+
+#[cfg(feature = "log")]
+pub use self::observer::LogObserver;
+
+#[cfg(feature = "indicatif")]
+pub use self::integrations::indicatif::drive_indicatif;
+
 #[cfg(any(test, feature = "test-utils"))]
 pub use self::progress::test_utils;
@@ -0,0 +1,55 @@
+//! A bounded buffer of recent messages.
+
+use std::collections::VecDeque;
+
+use crate::MessageEvent;
+
+/// A ring buffer retaining the most recent `capacity` messages.
+#[derive(Clone, Default, Debug)]
+pub(crate) struct MessageBuffer {
+    capacity: usize,
+    messages: VecDeque<MessageEvent>,
+}
+
+impl MessageBuffer {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            messages: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the buffer's capacity to `capacity`,
+    /// discarding the oldest messages that no longer fit.
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+
+        while self.messages.len() > capacity {
+            self.messages.pop_front();
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    pub(crate) fn push(&mut self, message: MessageEvent) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        while self.messages.len() >= self.capacity {
+            self.messages.pop_front();
+        }
+
+        self.messages.push_back(message);
+    }
+
+    pub(crate) fn to_vec(&self) -> Vec<MessageEvent> {
+        self.messages.iter().cloned().collect()
+    }
+}
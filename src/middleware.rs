@@ -0,0 +1,253 @@
+//! Composable observer middlewares.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use crate::{Event, MessageEvent, Observer, PriorityLevel};
+
+/// Types for intercepting events on their way to an `Observer`.
+pub trait ObserverMiddleware: Send + Sync {
+    /// Handles `event`, passing it on to `next` if it should be forwarded.
+    fn handle(&self, event: Event, next: &dyn Observer);
+}
+
+/// Implementation of `Observer` that passes events through
+/// a stack of middlewares before forwarding them to a sink.
+///
+/// Middlewares get applied in the order they were added,
+/// i.e. the first middleware added is the first to see an event:
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use sitrep::{FilterMiddleware, Layered, MeteringMiddleware, NopObserver, PriorityLevel};
+/// let observer = Layered::new(Arc::new(NopObserver))
+///     .with(FilterMiddleware::new(PriorityLevel::Warn))
+///     .with(MeteringMiddleware::default());
+/// ```
+pub struct Layered {
+    sink: Arc<dyn Observer>,
+    middlewares: Vec<Box<dyn ObserverMiddleware>>,
+}
+
+impl Layered {
+    /// Creates a layered observer forwarding to `sink`, without any middlewares.
+    pub fn new(sink: Arc<dyn Observer>) -> Self {
+        Self {
+            sink,
+            middlewares: vec![],
+        }
+    }
+
+    /// Builder-style method for adding `middleware` to the stack.
+    pub fn with(mut self, middleware: impl ObserverMiddleware + 'static) -> Self {
+        self.middlewares.push(Box::new(middleware));
+        self
+    }
+}
+
+impl Observer for Layered {
+    fn observe(&self, event: Event) {
+        Next {
+            middlewares: &self.middlewares,
+            sink: &*self.sink,
+        }
+        .observe(event);
+    }
+
+    fn is_active(&self) -> bool {
+        self.sink.is_active()
+    }
+}
+
+impl std::fmt::Debug for Layered {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Layered")
+            .field("middlewares", &self.middlewares.len())
+            .finish()
+    }
+}
+
+/// The remainder of a middleware stack.
+struct Next<'a> {
+    middlewares: &'a [Box<dyn ObserverMiddleware>],
+    sink: &'a dyn Observer,
+}
+
+impl Observer for Next<'_> {
+    fn observe(&self, event: Event) {
+        let Some((middleware, middlewares)) = self.middlewares.split_first() else {
+            self.sink.observe(event);
+            return;
+        };
+
+        let next = Next {
+            middlewares,
+            sink: self.sink,
+        };
+
+        middleware.handle(event, &next);
+    }
+}
+
+/// Implementation of `ObserverMiddleware` that drops
+/// message events below a minimum priority level.
+///
+/// All non-message events get forwarded unchanged.
+#[derive(Clone, Debug)]
+pub struct FilterMiddleware {
+    min_priority_level: PriorityLevel,
+}
+
+impl FilterMiddleware {
+    /// Creates a middleware dropping messages with a priority below `min_priority_level`.
+    pub fn new(min_priority_level: PriorityLevel) -> Self {
+        Self { min_priority_level }
+    }
+}
+
+impl ObserverMiddleware for FilterMiddleware {
+    fn handle(&self, event: Event, next: &dyn Observer) {
+        if let Event::Message(MessageEvent { priority, .. }) = &event {
+            if *priority < self.min_priority_level {
+                return;
+            }
+        }
+
+        next.observe(event);
+    }
+}
+
+#[derive(Default, Debug)]
+struct Meters {
+    updates: AtomicUsize,
+    messages: AtomicUsize,
+    attachments: AtomicUsize,
+    detachments: AtomicUsize,
+    state_changes: AtomicUsize,
+    generation_overflows: AtomicUsize,
+}
+
+/// Implementation of `ObserverMiddleware` that counts the events passing through it.
+///
+/// Clones share their counters, so a clone can be kept around
+/// for reading the counts after handing the middleware off to `Layered`.
+#[derive(Clone, Default, Debug)]
+pub struct MeteringMiddleware {
+    meters: Arc<Meters>,
+}
+
+impl MeteringMiddleware {
+    /// Returns the number of update events observed so far.
+    pub fn updates(&self) -> usize {
+        self.meters.updates.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of message events observed so far.
+    pub fn messages(&self) -> usize {
+        self.meters.messages.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of attachment events observed so far.
+    pub fn attachments(&self) -> usize {
+        self.meters.attachments.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of detachment events observed so far.
+    pub fn detachments(&self) -> usize {
+        self.meters.detachments.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of state change events observed so far.
+    pub fn state_changes(&self) -> usize {
+        self.meters.state_changes.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of generation overflow events observed so far.
+    pub fn generation_overflows(&self) -> usize {
+        self.meters.generation_overflows.load(Ordering::Relaxed)
+    }
+
+    /// Returns the total number of events observed so far.
+    pub fn total(&self) -> usize {
+        self.updates()
+            + self.messages()
+            + self.attachments()
+            + self.detachments()
+            + self.state_changes()
+            + self.generation_overflows()
+    }
+}
+
+impl ObserverMiddleware for MeteringMiddleware {
+    fn handle(&self, event: Event, next: &dyn Observer) {
+        let meter = match &event {
+            Event::Update(_) => &self.meters.updates,
+            Event::Message(_) => &self.meters.messages,
+            Event::Attachment(_) => &self.meters.attachments,
+            Event::Detachment(_) => &self.meters.detachments,
+            Event::StateChange(_) => &self.meters.state_changes,
+            Event::GenerationOverflow => &self.meters.generation_overflows,
+        };
+
+        meter.fetch_add(1, Ordering::Relaxed);
+
+        next.observe(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::{Progress, Task};
+
+    #[derive(Default)]
+    struct SpyObserver {
+        events: Mutex<Vec<Event>>,
+    }
+
+    impl Observer for SpyObserver {
+        fn observe(&self, event: Event) {
+            self.events.lock().unwrap().push(event);
+        }
+    }
+
+    #[test]
+    fn filter_and_metering() {
+        let sink = Arc::new(SpyObserver::default());
+        let metering = MeteringMiddleware::default();
+
+        let observer = Layered::new(Arc::<SpyObserver>::clone(&sink))
+            .with(FilterMiddleware::new(PriorityLevel::Warn))
+            .with(metering.clone());
+
+        let (progress, _reporter) = Progress::new(Task::default(), Arc::new(observer));
+
+        for level in PriorityLevel::ALL {
+            progress.message(|| "test", level);
+        }
+
+        progress.update(|_| {});
+
+        let priorities: Vec<_> = sink
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|event| match event {
+                Event::Message(event) => Some(event.priority),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(priorities, vec![PriorityLevel::Warn, PriorityLevel::Error]);
+
+        // The metering only gets to see what passed the filter:
+        assert_eq!(metering.messages(), 2);
+        assert_eq!(metering.updates(), 1);
+        assert_eq!(metering.total(), 3);
+    }
+}
@@ -1,17 +1,37 @@
-use std::sync::mpsc::Sender;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::Sender,
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
-use crate::{Event, Observer};
+use parking_lot::Mutex;
+
+use crate::{
+    AttachmentEvent, Clock, DetachmentEvent, Event, MessageEvent, Observer, PriorityLevel,
+    ProgressId, State, StateChangeEvent, UpdateEvent,
+};
 
 /// Implementation of `Observer` based on `std::sync::mpsc::Sender`.
+///
+/// The observer (and its clones) becomes inactive upon the first failure to send an event,
+/// i.e. once the receiving end of the channel has been dropped.
 #[derive(Clone, Debug)]
 pub struct StdMpscObserver {
     /// The sending-half of std's channel type.
     pub sender: Sender<Event>,
+    is_active: Arc<AtomicBool>,
 }
 
 impl From<Sender<Event>> for StdMpscObserver {
     fn from(sender: Sender<Event>) -> Self {
-        Self { sender }
+        Self {
+            sender,
+            is_active: Arc::new(AtomicBool::new(true)),
+        }
     }
 }
 
@@ -23,7 +43,13 @@ impl From<StdMpscObserver> for Sender<Event> {
 
 impl Observer for StdMpscObserver {
     fn observe(&self, event: Event) {
-        let _ = self.sender.send(event);
+        if self.sender.send(event).is_err() {
+            self.is_active.store(false, Ordering::Relaxed);
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.is_active.load(Ordering::Relaxed)
     }
 }
 
@@ -31,6 +57,262 @@ unsafe impl Send for StdMpscObserver where Event: Send {}
 
 unsafe impl Sync for StdMpscObserver where Event: Send {}
 
+/// Implementation of `Observer` based on `tokio::sync::mpsc::UnboundedSender`.
+///
+/// Sending events never blocks, making it suitable for bridging
+/// progress events into an async runtime.
+#[cfg(feature = "async-tokio")]
+#[derive(Clone, Debug)]
+pub struct TokioMpscObserver {
+    /// The sending-half of tokio's unbounded channel type.
+    pub sender: tokio::sync::mpsc::UnboundedSender<Event>,
+}
+
+#[cfg(feature = "async-tokio")]
+impl From<tokio::sync::mpsc::UnboundedSender<Event>> for TokioMpscObserver {
+    fn from(sender: tokio::sync::mpsc::UnboundedSender<Event>) -> Self {
+        Self { sender }
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+impl From<TokioMpscObserver> for tokio::sync::mpsc::UnboundedSender<Event> {
+    fn from(observer: TokioMpscObserver) -> Self {
+        observer.sender
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+impl Observer for TokioMpscObserver {
+    fn observe(&self, event: Event) {
+        let _ = self.sender.send(event);
+    }
+
+    fn is_active(&self) -> bool {
+        !self.sender.is_closed()
+    }
+}
+
+/// Implementation of `Observer` based on `crossbeam_channel::Sender`.
+///
+/// Sending events never blocks: events sent to a full or disconnected channel get dropped.
+///
+/// The observer (and its clones) becomes inactive upon the first attempt
+/// to send an event to a disconnected channel.
+#[cfg(feature = "crossbeam")]
+#[derive(Clone, Debug)]
+pub struct CrossbeamObserver {
+    /// The sending-half of crossbeam's channel type.
+    pub sender: crossbeam_channel::Sender<Event>,
+    is_active: Arc<AtomicBool>,
+}
+
+#[cfg(feature = "crossbeam")]
+impl From<crossbeam_channel::Sender<Event>> for CrossbeamObserver {
+    fn from(sender: crossbeam_channel::Sender<Event>) -> Self {
+        Self {
+            sender,
+            is_active: Arc::new(AtomicBool::new(true)),
+        }
+    }
+}
+
+#[cfg(feature = "crossbeam")]
+impl From<CrossbeamObserver> for crossbeam_channel::Sender<Event> {
+    fn from(observer: CrossbeamObserver) -> Self {
+        observer.sender
+    }
+}
+
+#[cfg(feature = "crossbeam")]
+impl Observer for CrossbeamObserver {
+    fn observe(&self, event: Event) {
+        if let Err(crossbeam_channel::TrySendError::Disconnected(_)) = self.sender.try_send(event) {
+            self.is_active.store(false, Ordering::Relaxed);
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.is_active.load(Ordering::Relaxed)
+    }
+}
+
+/// Implementation of `Observer` forwarding events to the current `tracing` subscriber.
+///
+/// Messages get emitted at the level corresponding to their priority level,
+/// all other events get emitted at the `trace` level.
+/// Each event includes the associated progress' identifier as `progress_id` field.
+#[cfg(feature = "tracing")]
+#[derive(Clone, Default, Debug)]
+pub struct TracingObserver;
+
+#[cfg(feature = "tracing")]
+impl Observer for TracingObserver {
+    fn observe(&self, event: Event) {
+        match event {
+            Event::Update(UpdateEvent { id }) => {
+                tracing::trace!(progress_id = id.as_raw(), "progress updated");
+            }
+            Event::Message(MessageEvent {
+                id,
+                message,
+                priority,
+                ..
+            }) => {
+                let progress_id = id.as_raw();
+                match priority {
+                    PriorityLevel::Trace => tracing::trace!(progress_id, "{message}"),
+                    PriorityLevel::Debug => tracing::debug!(progress_id, "{message}"),
+                    PriorityLevel::Info => tracing::info!(progress_id, "{message}"),
+                    PriorityLevel::Warn => tracing::warn!(progress_id, "{message}"),
+                    PriorityLevel::Error => tracing::error!(progress_id, "{message}"),
+                }
+            }
+            Event::Attachment(event) => {
+                tracing::trace!(
+                    progress_id = event.id.as_raw(),
+                    parent_id = event.parent_id.as_raw(),
+                    "progress attached"
+                );
+            }
+            Event::Detachment(DetachmentEvent { id }) => {
+                tracing::trace!(progress_id = id.as_raw(), "progress detached");
+            }
+            Event::StateChange(StateChangeEvent { id, old, new }) => {
+                tracing::trace!(
+                    progress_id = id.as_raw(),
+                    ?old,
+                    ?new,
+                    "progress changed state"
+                );
+            }
+            Event::GenerationOverflow => {
+                tracing::trace!("generation overflow");
+            }
+        }
+    }
+}
+
+/// Implementation of `Observer` forwarding events to the current `log` logger,
+/// using `"sitrep"` as target.
+///
+/// Messages get logged at the level corresponding to their priority level,
+/// all other events get logged at the `trace` level.
+#[cfg(feature = "log")]
+#[derive(Clone, Default, Debug)]
+pub struct LogObserver;
+
+#[cfg(feature = "log")]
+impl LogObserver {
+    const TARGET: &'static str = "sitrep";
+}
+
+#[cfg(feature = "log")]
+impl Observer for LogObserver {
+    fn observe(&self, event: Event) {
+        let target = Self::TARGET;
+
+        match event {
+            Event::Update(UpdateEvent { id }) => {
+                log::trace!(target: target, "progress {} updated", id.as_raw());
+            }
+            Event::Message(MessageEvent {
+                message, priority, ..
+            }) => {
+                let level = match priority {
+                    PriorityLevel::Trace => log::Level::Trace,
+                    PriorityLevel::Debug => log::Level::Debug,
+                    PriorityLevel::Info => log::Level::Info,
+                    PriorityLevel::Warn => log::Level::Warn,
+                    PriorityLevel::Error => log::Level::Error,
+                };
+                log::log!(target: target, level, "{message}");
+            }
+            Event::Attachment(event) => {
+                log::trace!(
+                    target: target,
+                    "progress {} attached to {}",
+                    event.id.as_raw(),
+                    event.parent_id.as_raw()
+                );
+            }
+            Event::Detachment(DetachmentEvent { id }) => {
+                log::trace!(target: target, "progress {} detached", id.as_raw());
+            }
+            Event::StateChange(StateChangeEvent { id, old, new }) => {
+                log::trace!(
+                    target: target,
+                    "progress {} changed state from {old:?} to {new:?}",
+                    id.as_raw()
+                );
+            }
+            Event::GenerationOverflow => {
+                log::trace!(target: target, "generation overflow");
+            }
+        }
+    }
+}
+
+/// Implementation of `Observer` writing events as JSON lines (i.e. one JSON object per line)
+/// to a writer (e.g. `stdout` or a socket).
+///
+/// The writer gets flushed after each line. Errors writing to it are ignored.
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub struct JsonLinesObserver<W> {
+    writer: Mutex<W>,
+}
+
+#[cfg(feature = "serde")]
+impl<W> JsonLinesObserver<W>
+where
+    W: std::io::Write,
+{
+    /// Creates an observer writing lines to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+        }
+    }
+
+    /// Consumes the observer, returning the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner()
+    }
+
+    fn write_line(&self, event: &Event) -> std::io::Result<()> {
+        self.write_lines(std::slice::from_ref(event))
+    }
+
+    fn write_lines(&self, events: &[Event]) -> std::io::Result<()> {
+        let mut lines = vec![];
+
+        for event in events {
+            serde_json::to_writer(&mut lines, event)?;
+            lines.push(b'\n');
+        }
+
+        let mut writer = self.writer.lock();
+
+        writer.write_all(&lines)?;
+        writer.flush()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<W> Observer for JsonLinesObserver<W>
+where
+    W: std::io::Write + Send,
+{
+    fn observe(&self, event: Event) {
+        let _ = self.write_line(&event);
+    }
+
+    fn observe_batch(&self, events: Vec<Event>) {
+        let _ = self.write_lines(&events);
+    }
+}
+
 /// Implementation of `Observer` that does nothing.
 #[derive(Clone, Debug)]
 pub struct NopObserver;
@@ -44,3 +326,1926 @@ impl Observer for NopObserver {
 unsafe impl Send for NopObserver where Event: Send {}
 
 unsafe impl Sync for NopObserver where Event: Send {}
+
+/// Implementation of `Observer` that invokes a closure for each event.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use sitrep::FnObserver;
+/// let observer = Arc::new(FnObserver::new(|event| println!("{event:?}")));
+/// ```
+#[derive(Clone)]
+pub struct FnObserver<F> {
+    f: F,
+}
+
+impl<F> FnObserver<F>
+where
+    F: Fn(Event) + Send + Sync,
+{
+    /// Creates an observer invoking `f` for each event.
+    pub fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<F> Observer for FnObserver<F>
+where
+    F: Fn(Event) + Send + Sync,
+{
+    fn observe(&self, event: Event) {
+        (self.f)(event)
+    }
+}
+
+impl<F> std::fmt::Debug for FnObserver<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FnObserver").finish_non_exhaustive()
+    }
+}
+
+/// Implementation of `Observer` that forwards each event to multiple observers.
+///
+/// Events get forwarded to the observers in the order they were added,
+/// i.e. each observer receives an event only after all observers
+/// added before it have received (i.e. returned from observing) it.
+#[derive(Clone, Default)]
+pub struct MultiObserver {
+    observers: Vec<Arc<dyn Observer>>,
+}
+
+impl MultiObserver {
+    /// Creates an observer forwarding events to each of `observers`, in order.
+    pub fn new(observers: Vec<Arc<dyn Observer>>) -> Self {
+        Self { observers }
+    }
+
+    /// Appends `observer` to the observers to forward events to.
+    pub fn push(&mut self, observer: Arc<dyn Observer>) {
+        self.observers.push(observer);
+    }
+}
+
+impl Observer for MultiObserver {
+    fn observe(&self, event: Event) {
+        let Some((last, observers)) = self.observers.split_last() else {
+            return;
+        };
+
+        for observer in observers {
+            observer.observe(event.clone());
+        }
+
+        last.observe(event);
+    }
+
+    fn observe_batch(&self, events: Vec<Event>) {
+        let Some((last, observers)) = self.observers.split_last() else {
+            return;
+        };
+
+        for observer in observers {
+            observer.observe_batch(events.clone());
+        }
+
+        last.observe_batch(events);
+    }
+
+    fn is_active(&self) -> bool {
+        self.observers.iter().any(|observer| observer.is_active())
+    }
+}
+
+impl std::fmt::Debug for MultiObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiObserver")
+            .field("observers", &self.observers.len())
+            .finish()
+    }
+}
+
+/// Implementation of `Observer` that only forwards events of progresses
+/// transitioning into a terminal state (i.e. `State::Finished` or `State::Canceled`)
+/// to an inner observer, dropping all other events.
+///
+/// This is useful for orchestration, where only the end of a task is of interest,
+/// rather than its incremental progress.
+#[derive(Clone)]
+pub struct TerminalObserver {
+    inner: Arc<dyn Observer>,
+}
+
+impl TerminalObserver {
+    /// Creates an observer forwarding terminal state changes to `inner`.
+    pub fn new(inner: Arc<dyn Observer>) -> Self {
+        Self { inner }
+    }
+}
+
+impl Observer for TerminalObserver {
+    fn observe(&self, event: Event) {
+        let Event::StateChange(StateChangeEvent { new, .. }) = &event else {
+            return;
+        };
+
+        if matches!(new, State::Finished | State::Canceled) {
+            self.inner.observe(event);
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.inner.is_active()
+    }
+}
+
+impl std::fmt::Debug for TerminalObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TerminalObserver").finish_non_exhaustive()
+    }
+}
+
+/// A summary of the events observed within a fixed window of time.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct WindowSummary {
+    /// The instant at which the window started.
+    pub start: Instant,
+    /// The instant at which the window ended.
+    pub end: Instant,
+    /// The number of update events observed within the window.
+    pub updates: usize,
+    /// The number of message events observed within the window, per priority level.
+    pub messages: BTreeMap<PriorityLevel, usize>,
+    /// The number of attachment events observed within the window.
+    pub attachments: usize,
+    /// The number of detachment events observed within the window.
+    pub detachments: usize,
+    /// The number of state change events observed within the window.
+    pub state_changes: usize,
+    /// The number of generation overflow events observed within the window.
+    pub generation_overflows: usize,
+}
+
+impl WindowSummary {
+    fn new(start: Instant, end: Instant) -> Self {
+        Self {
+            start,
+            end,
+            updates: 0,
+            messages: BTreeMap::new(),
+            attachments: 0,
+            detachments: 0,
+            state_changes: 0,
+            generation_overflows: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.updates == 0
+            && self.messages.is_empty()
+            && self.attachments == 0
+            && self.detachments == 0
+            && self.state_changes == 0
+            && self.generation_overflows == 0
+    }
+
+    fn count(&mut self, event: &Event) {
+        match event {
+            Event::Update(_) => self.updates += 1,
+            Event::Message(MessageEvent { priority, .. }) => {
+                *self.messages.entry(*priority).or_default() += 1
+            }
+            Event::Attachment(_) => self.attachments += 1,
+            Event::Detachment(_) => self.detachments += 1,
+            Event::StateChange(_) => self.state_changes += 1,
+            Event::GenerationOverflow => self.generation_overflows += 1,
+        }
+    }
+}
+
+/// Implementation of `Observer` that forwards events to an inner observer,
+/// while bucketing them into fixed windows of time (based on an injected `Clock`)
+/// and emitting a `WindowSummary` per window to a sink.
+///
+/// # Flushing
+///
+/// Rather than relying on a background timer thread, summaries get flushed lazily:
+/// a window's summary gets emitted upon observing the first event after the window ended,
+/// or upon an explicit call to `flush()`. This keeps the observer free of threads and timers,
+/// at the expense of a window's summary being delayed until further events arrive.
+/// Call `flush()` periodically (e.g. on a UI's frame tick) if timely summaries
+/// are needed during periods of inactivity.
+///
+/// Windows without any events don't produce a summary.
+pub struct WindowedAggregatorObserver {
+    inner: Arc<dyn Observer>,
+    clock: Arc<dyn Clock>,
+    window: Duration,
+    origin: Instant,
+    summary: Mutex<WindowSummary>,
+    sink: Box<dyn Fn(WindowSummary) + Send + Sync>,
+}
+
+impl WindowedAggregatorObserver {
+    /// Creates an observer forwarding events to `inner`,
+    /// while emitting a summary per `window` of time to `sink`.
+    ///
+    /// Windows are aligned to the observer's creation.
+    pub fn new(
+        inner: Arc<dyn Observer>,
+        window: Duration,
+        clock: Arc<dyn Clock>,
+        sink: impl Fn(WindowSummary) + Send + Sync + 'static,
+    ) -> Self {
+        assert!(!window.is_zero(), "window must not be zero");
+
+        let origin = clock.now();
+        let summary = Mutex::new(WindowSummary::new(origin, origin + window));
+
+        Self {
+            inner,
+            clock,
+            window,
+            origin,
+            summary,
+            sink: Box::new(sink),
+        }
+    }
+
+    /// Emits the current window's summary (if it isn't empty),
+    /// resetting its counts for the remainder of the window.
+    pub fn flush(&self) {
+        let mut summary = self.summary.lock();
+
+        let (start, end) = (summary.start, summary.end);
+
+        let summary = std::mem::replace(&mut *summary, WindowSummary::new(start, end));
+
+        if !summary.is_empty() {
+            (self.sink)(summary);
+        }
+    }
+
+    fn window_containing(&self, instant: Instant) -> (Instant, Instant) {
+        let elapsed = instant.saturating_duration_since(self.origin).as_nanos();
+        let window = self.window.as_nanos();
+
+        let offset = Duration::from_nanos(((elapsed / window) * window) as u64);
+
+        let start = self.origin + offset;
+        (start, start + self.window)
+    }
+}
+
+impl Observer for WindowedAggregatorObserver {
+    fn observe(&self, event: Event) {
+        let now = self.clock.now();
+
+        let expired = {
+            let mut summary = self.summary.lock();
+
+            let expired = if now >= summary.end {
+                let (start, end) = self.window_containing(now);
+                Some(std::mem::replace(
+                    &mut *summary,
+                    WindowSummary::new(start, end),
+                ))
+            } else {
+                None
+            };
+
+            summary.count(&event);
+
+            expired
+        };
+
+        if let Some(summary) = expired.filter(|summary| !summary.is_empty()) {
+            (self.sink)(summary);
+        }
+
+        self.inner.observe(event);
+    }
+
+    fn is_active(&self) -> bool {
+        self.inner.is_active()
+    }
+}
+
+impl std::fmt::Debug for WindowedAggregatorObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WindowedAggregatorObserver")
+            .field("window", &self.window)
+            .field("summary", &*self.summary.lock())
+            .finish()
+    }
+}
+
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+    pending_updates: BTreeSet<ProgressId>,
+}
+
+/// Implementation of `Observer` that limits the rate of update events
+/// forwarded to an inner observer, based on a token bucket.
+///
+/// The bucket holds up to one second's worth of tokens (i.e. allowing for bursts
+/// of up to `events_per_second` update events) and gets refilled continuously
+/// at a rate of `events_per_second`, based on an injected `Clock`.
+///
+/// Once the bucket is exhausted excess update events get coalesced, keeping only
+/// a single pending update per progress, which get forwarded as soon as tokens
+/// become available again upon observing subsequent events, or upon an explicit
+/// call to `flush()`.
+///
+/// All other events (i.e. messages, state changes, attachments, detachments, …)
+/// are always forwarded immediately, without consuming any tokens.
+pub struct RateLimitObserver {
+    inner: Arc<dyn Observer>,
+    clock: Arc<dyn Clock>,
+    events_per_second: f64,
+    bucket: Mutex<TokenBucket>,
+}
+
+impl RateLimitObserver {
+    /// Creates an observer forwarding at most `events_per_second` update events
+    /// per second (on average) to `inner`.
+    pub fn new(inner: Arc<dyn Observer>, events_per_second: f64, clock: Arc<dyn Clock>) -> Self {
+        assert!(
+            events_per_second > 0.0,
+            "events per second must be greater than zero"
+        );
+
+        let bucket = Mutex::new(TokenBucket {
+            tokens: events_per_second,
+            last_refill: clock.now(),
+            pending_updates: BTreeSet::new(),
+        });
+
+        Self {
+            inner,
+            clock,
+            events_per_second,
+            bucket,
+        }
+    }
+
+    /// Forwards all pending coalesced update events, regardless of available tokens.
+    pub fn flush(&self) {
+        let pending_updates = std::mem::take(&mut self.bucket.lock().pending_updates);
+
+        for id in pending_updates {
+            self.inner.observe(Event::Update(UpdateEvent { id }));
+        }
+    }
+
+    /// Refills the bucket and takes as many pending updates
+    /// (followed by `update`, if any) as there are tokens available.
+    fn take_forwardable(&self, update: Option<ProgressId>) -> Vec<ProgressId> {
+        let now = self.clock.now();
+
+        let bucket = &mut *self.bucket.lock();
+
+        let elapsed = now.saturating_duration_since(bucket.last_refill);
+        bucket.tokens = (bucket.tokens + elapsed.as_secs_f64() * self.events_per_second)
+            .min(self.events_per_second);
+        bucket.last_refill = now;
+
+        if let Some(id) = update {
+            bucket.pending_updates.insert(id);
+        }
+
+        let mut forwardable = vec![];
+
+        while bucket.tokens >= 1.0 {
+            let Some(id) = bucket.pending_updates.pop_first() else {
+                break;
+            };
+
+            bucket.tokens -= 1.0;
+            forwardable.push(id);
+        }
+
+        forwardable
+    }
+}
+
+impl Observer for RateLimitObserver {
+    fn observe(&self, event: Event) {
+        let update = match &event {
+            Event::Update(UpdateEvent { id }) => Some(*id),
+            Event::Detachment(DetachmentEvent { id }) => {
+                // Pending updates of detached progresses are no longer of interest:
+                self.bucket.lock().pending_updates.remove(id);
+                None
+            }
+            _ => None,
+        };
+
+        for id in self.take_forwardable(update) {
+            self.inner.observe(Event::Update(UpdateEvent { id }));
+        }
+
+        if update.is_none() {
+            self.inner.observe(event);
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.inner.is_active()
+    }
+}
+
+impl std::fmt::Debug for RateLimitObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateLimitObserver")
+            .field("events_per_second", &self.events_per_second)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Implementation of `Observer` buffering events in a fixed-capacity ring buffer,
+/// to be polled via `drain()`.
+///
+/// Update events are coalesced: an update event for a progress
+/// supersedes any older buffered update event for the same progress.
+///
+/// Once the buffer is full, buffering another event drops the oldest non-message event,
+/// or the oldest message event if the buffer contains nothing but message events.
+pub struct RingBufferObserver {
+    capacity: usize,
+    events: Mutex<VecDeque<Event>>,
+}
+
+impl RingBufferObserver {
+    /// Creates an observer buffering at most `capacity` events.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be greater than zero");
+
+        Self {
+            capacity,
+            events: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Returns the observer's capacity.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Removes and returns all buffered events, in the order they were observed.
+    pub fn drain(&self) -> Vec<Event> {
+        self.events.lock().drain(..).collect()
+    }
+}
+
+impl Observer for RingBufferObserver {
+    fn observe(&self, event: Event) {
+        let mut events = self.events.lock();
+
+        if let Event::Update(UpdateEvent { id }) = &event {
+            events
+                .retain(|buffered| !matches!(buffered, Event::Update(update) if update.id == *id));
+        }
+
+        if events.len() >= self.capacity {
+            let oldest_non_message = events
+                .iter()
+                .position(|buffered| !matches!(buffered, Event::Message(_)));
+
+            events.remove(oldest_non_message.unwrap_or(0));
+        }
+
+        events.push_back(event);
+    }
+}
+
+impl std::fmt::Debug for RingBufferObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RingBufferObserver")
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Default)]
+struct CoalescingQueue {
+    pending_updates: HashSet<ProgressId>,
+    events: Vec<Event>,
+}
+
+/// Implementation of `Observer` buffering events, to be polled via `drain()`,
+/// while coalescing redundant update events.
+///
+/// Between two drains at most one update event gets buffered per progress
+/// (at the position of the progress' first update since the last drain),
+/// while all other events get buffered as is, preserving their order.
+#[derive(Default)]
+pub struct CoalescingObserver {
+    queue: Mutex<CoalescingQueue>,
+}
+
+impl CoalescingObserver {
+    /// Creates an observer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes and returns all buffered events, in the order they were observed.
+    pub fn drain(&self) -> Vec<Event> {
+        let mut queue = self.queue.lock();
+
+        queue.pending_updates.clear();
+
+        std::mem::take(&mut queue.events)
+    }
+}
+
+impl Observer for CoalescingObserver {
+    fn observe(&self, event: Event) {
+        let mut queue = self.queue.lock();
+
+        if let Event::Update(UpdateEvent { id }) = &event {
+            if !queue.pending_updates.insert(*id) {
+                return;
+            }
+        }
+
+        queue.events.push(event);
+    }
+}
+
+impl std::fmt::Debug for CoalescingObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CoalescingObserver").finish_non_exhaustive()
+    }
+}
+
+#[derive(Default)]
+struct PollQueue {
+    events: Vec<Event>,
+    is_report_dirty: bool,
+}
+
+/// Implementation of `Observer` buffering events, to be polled via `poll()`
+/// (e.g. on a UI's frame tick), rather than having to be received on a dedicated thread.
+///
+/// Besides buffering the events themselves the observer keeps track of whether
+/// any update event has been observed, allowing for cheaply checking
+/// if a fresh report needs to be generated via `latest_report_dirty()`.
+#[derive(Default)]
+pub struct PollObserver {
+    queue: Mutex<PollQueue>,
+}
+
+impl PollObserver {
+    /// Creates an observer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes and returns all buffered events, in the order they were observed.
+    pub fn poll(&self) -> Vec<Event> {
+        std::mem::take(&mut self.queue.lock().events)
+    }
+
+    /// Returns `true` if any update event has been observed since the last call
+    /// to this method (i.e. if previously generated reports are stale), otherwise `false`.
+    ///
+    /// Calling this method resets the flag, independently of `poll()`.
+    pub fn latest_report_dirty(&self) -> bool {
+        std::mem::take(&mut self.queue.lock().is_report_dirty)
+    }
+}
+
+impl Observer for PollObserver {
+    fn observe(&self, event: Event) {
+        let mut queue = self.queue.lock();
+
+        if let Event::Update(_) = &event {
+            queue.is_report_dirty = true;
+        }
+
+        queue.events.push(event);
+    }
+}
+
+impl std::fmt::Debug for PollObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PollObserver").finish_non_exhaustive()
+    }
+}
+
+#[derive(Default)]
+struct Remapping {
+    external_ids: HashMap<ProgressId, u64>,
+    next_external_id: u64,
+}
+
+impl Remapping {
+    fn remap(&mut self, id: ProgressId) -> ProgressId {
+        let next_external_id = &mut self.next_external_id;
+
+        let external_id = *self.external_ids.entry(id).or_insert_with(|| {
+            let external_id = *next_external_id;
+            *next_external_id += 1;
+            external_id
+        });
+
+        // Truncates external identifiers beyond `usize::MAX` on 32-bit platforms:
+        ProgressId::from_raw(external_id as usize)
+    }
+}
+
+/// Implementation of `Observer` that forwards events to an inner observer,
+/// while rewriting their progress identifiers into an external, caller-controlled
+/// identifier space (e.g. one that got persisted across process restarts).
+///
+/// Each progress gets assigned the next available external identifier upon its first
+/// sighting, which it then keeps (even after getting detached).
+///
+/// As the forwarded events carry the external identifiers as `ProgressId`s,
+/// they may collide with the identifiers of unrelated live progresses,
+/// hence they must not be mixed with non-remapped events,
+/// nor be used for looking up progresses (e.g. via [`Controller::get()`](crate::Controller::get)).
+pub struct RemappingObserver {
+    inner: Arc<dyn Observer>,
+    remapping: Mutex<Remapping>,
+}
+
+impl RemappingObserver {
+    /// Creates an observer forwarding remapped events to `inner`,
+    /// assigning external identifiers starting at `first_external_id`.
+    pub fn new(inner: Arc<dyn Observer>, first_external_id: u64) -> Self {
+        Self::with_mapping(inner, HashMap::new(), first_external_id)
+    }
+
+    /// Creates an observer forwarding remapped events to `inner`,
+    /// based on an existing (e.g. restored) `mapping` from progress identifiers
+    /// to external identifiers, assigning new external identifiers starting
+    /// at `next_external_id`.
+    pub fn with_mapping(
+        inner: Arc<dyn Observer>,
+        mapping: HashMap<ProgressId, u64>,
+        next_external_id: u64,
+    ) -> Self {
+        let remapping = Mutex::new(Remapping {
+            external_ids: mapping,
+            next_external_id,
+        });
+
+        Self { inner, remapping }
+    }
+
+    /// Returns the current mapping from progress identifiers to external identifiers.
+    pub fn mapping(&self) -> HashMap<ProgressId, u64> {
+        self.remapping.lock().external_ids.clone()
+    }
+
+    /// Returns the external identifier assigned to the progress with the given `id`, if any.
+    pub fn external_id(&self, id: ProgressId) -> Option<u64> {
+        self.remapping.lock().external_ids.get(&id).copied()
+    }
+
+    fn remap(&self, event: Event) -> Event {
+        let remapping = &mut *self.remapping.lock();
+
+        match event {
+            Event::Update(UpdateEvent { id }) => Event::Update(UpdateEvent {
+                id: remapping.remap(id),
+            }),
+            Event::Message(event) => Event::Message(MessageEvent {
+                id: remapping.remap(event.id),
+                ..event
+            }),
+            Event::Attachment(AttachmentEvent { id, parent_id }) => {
+                // Make sure parents get assigned their identifiers before their children:
+                let parent_id = remapping.remap(parent_id);
+                let id = remapping.remap(id);
+                Event::Attachment(AttachmentEvent { id, parent_id })
+            }
+            Event::Detachment(DetachmentEvent { id }) => Event::Detachment(DetachmentEvent {
+                id: remapping.remap(id),
+            }),
+            Event::StateChange(event) => Event::StateChange(StateChangeEvent {
+                id: remapping.remap(event.id),
+                ..event
+            }),
+            Event::GenerationOverflow => Event::GenerationOverflow,
+        }
+    }
+}
+
+impl Observer for RemappingObserver {
+    fn observe(&self, event: Event) {
+        let event = self.remap(event);
+
+        self.inner.observe(event);
+    }
+
+    fn is_active(&self) -> bool {
+        self.inner.is_active()
+    }
+}
+
+impl std::fmt::Debug for RemappingObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemappingObserver").finish_non_exhaustive()
+    }
+}
+
+struct Debounce {
+    last_forward: Option<Instant>,
+    pending_updates: BTreeSet<ProgressId>,
+}
+
+/// Implementation of `Observer` that debounces update events forwarded
+/// to an inner observer, forwarding them at most once per interval of time
+/// (based on an injected `Clock`).
+///
+/// Update events observed within an interval get coalesced, keeping only
+/// a single pending update per progress, which get forwarded together
+/// once the interval has elapsed since the last forwarding.
+///
+/// All other events (i.e. messages, state changes, attachments, detachments, …)
+/// are always forwarded immediately, preceded by any due pending updates.
+///
+/// # Flushing
+///
+/// Rather than relying on a background timer thread, pending updates get flushed lazily:
+/// they get forwarded upon observing the first event after the interval elapsed,
+/// or upon an explicit call to `flush()`. Call `flush()` periodically (e.g. on a UI's
+/// frame tick) to make sure the final updates of a burst don't get held back
+/// during periods of inactivity.
+pub struct DebouncingObserver {
+    inner: Arc<dyn Observer>,
+    clock: Arc<dyn Clock>,
+    interval: Duration,
+    debounce: Mutex<Debounce>,
+}
+
+impl DebouncingObserver {
+    /// Creates an observer forwarding update events to `inner`
+    /// at most once per `interval`.
+    pub fn new(inner: Arc<dyn Observer>, interval: Duration, clock: Arc<dyn Clock>) -> Self {
+        let debounce = Mutex::new(Debounce {
+            last_forward: None,
+            pending_updates: BTreeSet::new(),
+        });
+
+        Self {
+            inner,
+            clock,
+            interval,
+            debounce,
+        }
+    }
+
+    /// Forwards all pending coalesced update events, regardless of the interval.
+    pub fn flush(&self) {
+        let now = self.clock.now();
+
+        let pending_updates = {
+            let mut debounce = self.debounce.lock();
+            debounce.last_forward = Some(now);
+            std::mem::take(&mut debounce.pending_updates)
+        };
+
+        for id in pending_updates {
+            self.inner.observe(Event::Update(UpdateEvent { id }));
+        }
+    }
+
+    /// Takes all pending updates (including `update`, if any),
+    /// if the interval has elapsed since the last forwarding.
+    fn take_forwardable(&self, update: Option<ProgressId>) -> BTreeSet<ProgressId> {
+        let now = self.clock.now();
+
+        let debounce = &mut *self.debounce.lock();
+
+        if let Some(id) = update {
+            debounce.pending_updates.insert(id);
+        }
+
+        let is_due = debounce.last_forward.map_or(true, |last_forward| {
+            now.saturating_duration_since(last_forward) >= self.interval
+        });
+
+        if !is_due || debounce.pending_updates.is_empty() {
+            return BTreeSet::new();
+        }
+
+        debounce.last_forward = Some(now);
+
+        std::mem::take(&mut debounce.pending_updates)
+    }
+}
+
+impl Observer for DebouncingObserver {
+    fn observe(&self, event: Event) {
+        let update = match &event {
+            Event::Update(UpdateEvent { id }) => Some(*id),
+            Event::Detachment(DetachmentEvent { id }) => {
+                // Pending updates of detached progresses are no longer of interest:
+                self.debounce.lock().pending_updates.remove(id);
+                None
+            }
+            _ => None,
+        };
+
+        for id in self.take_forwardable(update) {
+            self.inner.observe(Event::Update(UpdateEvent { id }));
+        }
+
+        if update.is_none() {
+            self.inner.observe(event);
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.inner.is_active()
+    }
+}
+
+impl std::fmt::Debug for DebouncingObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DebouncingObserver")
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct SpyObserver {
+        events: Mutex<Vec<Event>>,
+        batch_lens: Mutex<Vec<usize>>,
+    }
+
+    impl Observer for SpyObserver {
+        fn observe(&self, event: Event) {
+            self.events.lock().push(event);
+        }
+
+        fn observe_batch(&self, events: Vec<Event>) {
+            self.batch_lens.lock().push(events.len());
+            self.events.lock().extend(events);
+        }
+    }
+
+    mod std_mpsc {
+        use std::sync::mpsc;
+
+        use crate::{Progress, Task};
+
+        use super::*;
+
+        #[test]
+        fn dropped_receiver() {
+            let (sender, receiver) = mpsc::channel();
+            let observer = StdMpscObserver::from(sender);
+            let clone = observer.clone();
+
+            let (progress, _) = Progress::new(Task::default(), Arc::new(observer));
+
+            progress.set_completed(1);
+
+            assert!(clone.is_active());
+            assert_eq!(receiver.try_iter().count(), 1);
+
+            drop(receiver);
+
+            assert!(clone.is_active());
+
+            progress.set_completed(2);
+
+            assert!(!clone.is_active());
+        }
+    }
+
+    #[cfg(feature = "async-tokio")]
+    mod tokio_mpsc {
+        use crate::{Progress, Task};
+
+        use super::*;
+
+        #[test]
+        fn in_order() {
+            let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+            let observer = Arc::new(TokioMpscObserver::from(sender));
+
+            let (progress, _) = Progress::new(Task::default().total(3), observer);
+
+            progress.set_completed(1);
+            progress.message(|| "test", PriorityLevel::Info);
+            progress.set_completed(2);
+
+            let id = progress.id();
+
+            assert_eq!(
+                receiver.try_recv().unwrap(),
+                Event::Update(UpdateEvent { id })
+            );
+            assert!(matches!(
+                receiver.try_recv().unwrap(),
+                Event::Message(MessageEvent { message, .. }) if message == "test"
+            ));
+            assert_eq!(
+                receiver.try_recv().unwrap(),
+                Event::Update(UpdateEvent { id })
+            );
+            assert!(receiver.try_recv().is_err());
+        }
+
+        #[test]
+        fn dropped_receiver() {
+            let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+            let observer = TokioMpscObserver::from(sender);
+
+            assert!(observer.is_active());
+
+            drop(receiver);
+
+            assert!(!observer.is_active());
+        }
+    }
+
+    #[cfg(feature = "crossbeam")]
+    mod crossbeam {
+        use crate::{Progress, Task};
+
+        use super::*;
+
+        #[test]
+        fn in_order() {
+            let (sender, receiver) = crossbeam_channel::unbounded();
+            let observer = Arc::new(CrossbeamObserver::from(sender));
+
+            let (progress, _) = Progress::new(Task::default().total(3), observer);
+
+            progress.set_completed(1);
+            progress.message(|| "test", PriorityLevel::Info);
+            progress.set_completed(2);
+
+            let id = progress.id();
+
+            assert_eq!(
+                receiver.try_recv().unwrap(),
+                Event::Update(UpdateEvent { id })
+            );
+            assert!(matches!(
+                receiver.try_recv().unwrap(),
+                Event::Message(MessageEvent { message, .. }) if message == "test"
+            ));
+            assert_eq!(
+                receiver.try_recv().unwrap(),
+                Event::Update(UpdateEvent { id })
+            );
+            assert!(receiver.try_recv().is_err());
+        }
+
+        #[test]
+        fn dropped_receiver() {
+            let (sender, receiver) = crossbeam_channel::bounded(1);
+            let observer = CrossbeamObserver::from(sender);
+
+            let id = ProgressId::new_unique();
+
+            // Full channel:
+            observer.observe(Event::Update(UpdateEvent { id }));
+            observer.observe(Event::Update(UpdateEvent { id }));
+
+            assert!(observer.is_active());
+
+            drop(receiver);
+
+            assert!(observer.is_active());
+
+            // Disconnected channel:
+            observer.observe(Event::Update(UpdateEvent { id }));
+
+            assert!(!observer.is_active());
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    mod tracing_observer {
+        use tracing::{
+            field::{Field, Visit},
+            span, Level, Metadata, Subscriber,
+        };
+
+        use super::*;
+
+        type CapturedEvent = (Level, Option<u64>);
+
+        #[derive(Default)]
+        struct CapturingSubscriber {
+            events: Arc<Mutex<Vec<CapturedEvent>>>,
+        }
+
+        #[derive(Default)]
+        struct ProgressIdVisitor(Option<u64>);
+
+        impl Visit for ProgressIdVisitor {
+            fn record_u64(&mut self, field: &Field, value: u64) {
+                if field.name() == "progress_id" {
+                    self.0 = Some(value);
+                }
+            }
+
+            fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+        }
+
+        impl Subscriber for CapturingSubscriber {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+                span::Id::from_u64(1)
+            }
+
+            fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+            fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+            fn event(&self, event: &tracing::Event<'_>) {
+                let mut visitor = ProgressIdVisitor::default();
+                event.record(&mut visitor);
+
+                let level = *event.metadata().level();
+                self.events.lock().push((level, visitor.0));
+            }
+
+            fn enter(&self, _span: &span::Id) {}
+
+            fn exit(&self, _span: &span::Id) {}
+        }
+
+        #[test]
+        fn level_mapping() {
+            let subscriber = CapturingSubscriber::default();
+            let events = Arc::clone(&subscriber.events);
+
+            let id = ProgressId::new_unique();
+            let raw_id = Some(id.as_raw() as u64);
+
+            tracing::subscriber::with_default(subscriber, || {
+                let observer = TracingObserver;
+
+                observer.observe(Event::Update(UpdateEvent { id }));
+
+                for priority in PriorityLevel::ALL {
+                    observer.observe(Event::Message(MessageEvent {
+                        id,
+                        message: "message".into(),
+                        priority,
+                        fields: vec![],
+                    }));
+                }
+
+                observer.observe(Event::Detachment(DetachmentEvent { id }));
+            });
+
+            assert_eq!(
+                events.lock().as_slice(),
+                &[
+                    (Level::TRACE, raw_id),
+                    (Level::TRACE, raw_id),
+                    (Level::DEBUG, raw_id),
+                    (Level::INFO, raw_id),
+                    (Level::WARN, raw_id),
+                    (Level::ERROR, raw_id),
+                    (Level::TRACE, raw_id),
+                ]
+            );
+        }
+    }
+
+    #[cfg(feature = "log")]
+    mod log_observer {
+        use log::{Level, Log, Metadata, Record};
+
+        use super::*;
+
+        struct CapturingLogger {
+            records: Mutex<Vec<(Level, String)>>,
+        }
+
+        impl Log for CapturingLogger {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn log(&self, record: &Record<'_>) {
+                if record.target() == LogObserver::TARGET {
+                    let message = record.args().to_string();
+                    self.records.lock().push((record.level(), message));
+                }
+            }
+
+            fn flush(&self) {}
+        }
+
+        static LOGGER: CapturingLogger = CapturingLogger {
+            records: Mutex::new(Vec::new()),
+        };
+
+        #[test]
+        fn level_mapping() {
+            log::set_logger(&LOGGER).unwrap();
+            log::set_max_level(log::LevelFilter::Trace);
+
+            let observer = LogObserver;
+
+            let id = ProgressId::new_unique();
+
+            for (priority, message) in PriorityLevel::ALL
+                .into_iter()
+                .zip(["a", "b", "c", "d", "e"])
+            {
+                observer.observe(Event::Message(MessageEvent {
+                    id,
+                    message: message.into(),
+                    priority,
+                    fields: vec![],
+                }));
+            }
+
+            observer.observe(Event::Update(UpdateEvent { id }));
+
+            assert_eq!(
+                LOGGER.records.lock().as_slice(),
+                &[
+                    (Level::Trace, "a".to_owned()),
+                    (Level::Debug, "b".to_owned()),
+                    (Level::Info, "c".to_owned()),
+                    (Level::Warn, "d".to_owned()),
+                    (Level::Error, "e".to_owned()),
+                    (Level::Trace, format!("progress {} updated", id.as_raw())),
+                ]
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    mod json_lines {
+        use crate::{AttachmentEvent, StateChangeEvent};
+
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            let id = ProgressId::new_unique();
+
+            let events = vec![
+                Event::Update(UpdateEvent { id }),
+                Event::Message(MessageEvent {
+                    id,
+                    message: "multi\nline".into(),
+                    priority: PriorityLevel::Warn,
+                    fields: vec![("key".into(), "value".into())],
+                }),
+                Event::Attachment(AttachmentEvent {
+                    id,
+                    parent_id: ProgressId::new_unique(),
+                }),
+                Event::Detachment(DetachmentEvent { id }),
+                Event::StateChange(StateChangeEvent {
+                    id,
+                    old: State::Running,
+                    new: State::Finished,
+                }),
+                Event::GenerationOverflow,
+            ];
+
+            let observer = JsonLinesObserver::new(Vec::new());
+
+            for event in events.iter().cloned() {
+                observer.observe(event);
+            }
+
+            let bytes = observer.into_inner();
+            let text = String::from_utf8(bytes).unwrap();
+
+            let actual: Vec<Event> = text
+                .lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect();
+
+            assert_eq!(actual, events);
+        }
+
+        #[test]
+        fn batch() {
+            let id = ProgressId::new_unique();
+
+            let events = vec![
+                Event::Update(UpdateEvent { id }),
+                Event::Detachment(DetachmentEvent { id }),
+            ];
+
+            let observer = JsonLinesObserver::new(Vec::new());
+
+            observer.observe_batch(events.clone());
+
+            let bytes = observer.into_inner();
+            let text = String::from_utf8(bytes).unwrap();
+
+            let actual: Vec<Event> = text
+                .lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect();
+
+            assert_eq!(actual, events);
+        }
+    }
+
+    mod ring_buffer {
+        use super::*;
+
+        fn message(id: ProgressId, message: &'static str) -> Event {
+            Event::Message(MessageEvent {
+                id,
+                message: message.into(),
+                priority: PriorityLevel::Info,
+                fields: vec![],
+            })
+        }
+
+        #[test]
+        fn bounded_flooding() {
+            let observer = RingBufferObserver::new(8);
+
+            let ids: Vec<_> = (0..100).map(|_| ProgressId::new_unique()).collect();
+
+            for _ in 0..10 {
+                for &id in &ids {
+                    observer.observe(Event::Update(UpdateEvent { id }));
+                }
+            }
+
+            let events = observer.drain();
+
+            assert_eq!(events.len(), 8);
+            assert_eq!(
+                events.last(),
+                Some(&Event::Update(UpdateEvent { id: ids[99] }))
+            );
+            assert_eq!(observer.drain(), vec![]);
+        }
+
+        #[test]
+        fn coalesces_updates() {
+            let observer = RingBufferObserver::new(8);
+
+            let id = ProgressId::new_unique();
+
+            for _ in 0..1000 {
+                observer.observe(Event::Update(UpdateEvent { id }));
+            }
+
+            assert_eq!(observer.drain(), vec![Event::Update(UpdateEvent { id })]);
+        }
+
+        #[test]
+        fn drops_updates_before_messages() {
+            let observer = RingBufferObserver::new(3);
+
+            let id = ProgressId::new_unique();
+            let other_id = ProgressId::new_unique();
+
+            observer.observe(message(id, "a"));
+            observer.observe(Event::Update(UpdateEvent { id }));
+            observer.observe(message(id, "b"));
+            observer.observe(Event::Update(UpdateEvent { id: other_id }));
+
+            assert_eq!(
+                observer.drain(),
+                vec![
+                    message(id, "a"),
+                    message(id, "b"),
+                    Event::Update(UpdateEvent { id: other_id }),
+                ]
+            );
+
+            observer.observe(message(id, "a"));
+            observer.observe(message(id, "b"));
+            observer.observe(message(id, "c"));
+            observer.observe(message(id, "d"));
+
+            assert_eq!(
+                observer.drain(),
+                vec![message(id, "b"), message(id, "c"), message(id, "d")]
+            );
+        }
+    }
+
+    mod coalescing {
+        use crate::{Progress, Task};
+
+        use super::*;
+
+        #[test]
+        fn single_update_per_id() {
+            let observer = Arc::new(CoalescingObserver::new());
+
+            let (progress, _) = Progress::new(Task::default().total(1000), observer.clone());
+
+            for completed in 1..=1000 {
+                progress.set_completed(completed);
+            }
+
+            let id = progress.id();
+
+            assert_eq!(observer.drain(), vec![Event::Update(UpdateEvent { id })]);
+            assert_eq!(observer.drain(), vec![]);
+
+            progress.set_completed(0);
+
+            assert_eq!(observer.drain(), vec![Event::Update(UpdateEvent { id })]);
+        }
+
+        #[test]
+        fn preserves_messages() {
+            let observer = CoalescingObserver::new();
+
+            let id = ProgressId::new_unique();
+            let other_id = ProgressId::new_unique();
+
+            let message = |message: &'static str| {
+                Event::Message(MessageEvent {
+                    id,
+                    message: message.into(),
+                    priority: PriorityLevel::Info,
+                    fields: vec![],
+                })
+            };
+
+            observer.observe(Event::Update(UpdateEvent { id }));
+            observer.observe(message("a"));
+            observer.observe(Event::Update(UpdateEvent { id: other_id }));
+            observer.observe(Event::Update(UpdateEvent { id }));
+            observer.observe(message("b"));
+
+            assert_eq!(
+                observer.drain(),
+                vec![
+                    Event::Update(UpdateEvent { id }),
+                    message("a"),
+                    Event::Update(UpdateEvent { id: other_id }),
+                    message("b"),
+                ]
+            );
+        }
+    }
+
+    mod fn_observer {
+        use crate::{Progress, Task};
+
+        use super::*;
+
+        #[test]
+        fn receives_events() {
+            let events = Arc::new(Mutex::new(vec![]));
+
+            let observer = {
+                let events = Arc::clone(&events);
+                FnObserver::new(move |event| events.lock().push(event))
+            };
+
+            let (progress, _) = Progress::new(Task::default(), Arc::new(observer));
+
+            progress.set_total(2);
+            progress.message(|| "test", PriorityLevel::Warn);
+
+            let id = progress.id();
+
+            assert_eq!(
+                *events.lock(),
+                vec![
+                    Event::Update(UpdateEvent { id }),
+                    Event::Message(MessageEvent {
+                        id,
+                        message: "test".into(),
+                        priority: PriorityLevel::Warn,
+                        fields: vec![],
+                    }),
+                ]
+            );
+        }
+    }
+
+    mod multi {
+        use crate::{Progress, Task};
+
+        use super::*;
+
+        struct InactiveObserver;
+
+        impl Observer for InactiveObserver {
+            fn observe(&self, _event: Event) {}
+
+            fn is_active(&self) -> bool {
+                false
+            }
+        }
+
+        #[test]
+        fn is_active() {
+            let mut observer = MultiObserver::new(vec![Arc::new(InactiveObserver)]);
+
+            assert!(!observer.is_active());
+
+            observer.push(Arc::new(SpyObserver::default()));
+
+            assert!(observer.is_active());
+        }
+
+        #[test]
+        fn fan_out() {
+            let first = Arc::new(SpyObserver::default());
+            let second = Arc::new(SpyObserver::default());
+
+            let mut observer = MultiObserver::new(vec![Arc::<SpyObserver>::clone(&first)]);
+            observer.push(Arc::<SpyObserver>::clone(&second));
+
+            let (progress, _) = Progress::new(Task::default(), Arc::new(observer));
+
+            progress.set_total(2);
+            progress.message(|| "test", PriorityLevel::Info);
+            progress.set_completed(1);
+
+            assert_eq!(first.events.lock().len(), 3);
+            assert_eq!(*first.events.lock(), *second.events.lock());
+        }
+
+        #[test]
+        fn fan_out_batch() {
+            let first = Arc::new(SpyObserver::default());
+            let second = Arc::new(SpyObserver::default());
+
+            let observer = MultiObserver::new(vec![
+                Arc::<SpyObserver>::clone(&first),
+                Arc::<SpyObserver>::clone(&second),
+            ]);
+
+            let id = ProgressId::new_unique();
+            let events = vec![
+                Event::Update(UpdateEvent { id }),
+                Event::Detachment(DetachmentEvent { id }),
+            ];
+
+            observer.observe_batch(events.clone());
+
+            for spy in [first, second] {
+                assert_eq!(*spy.batch_lens.lock(), vec![2]);
+                assert_eq!(*spy.events.lock(), events);
+            }
+        }
+    }
+
+    mod terminal {
+        use crate::{Controller, Progress, Task};
+
+        use super::*;
+
+        #[test]
+        fn finished() {
+            let spy = Arc::new(SpyObserver::default());
+            let observer = TerminalObserver::new(Arc::<SpyObserver>::clone(&spy));
+
+            let (progress, _) = Progress::new(Task::default().total(3), Arc::new(observer));
+
+            for completed in 1..=3 {
+                progress.set_completed(completed);
+                progress.message(|| "working", PriorityLevel::Info);
+            }
+
+            progress.set_state(State::Finished);
+
+            assert_eq!(
+                *spy.events.lock(),
+                vec![Event::StateChange(StateChangeEvent {
+                    id: progress.id(),
+                    old: State::Running,
+                    new: State::Finished,
+                })]
+            );
+        }
+
+        #[test]
+        fn canceled() {
+            let spy = Arc::new(SpyObserver::default());
+            let observer = TerminalObserver::new(Arc::<SpyObserver>::clone(&spy));
+
+            let (progress, _) = Progress::new(
+                Task::default().total(3).cancelable(true).pausable(true),
+                Arc::new(observer),
+            );
+
+            progress.set_completed(1);
+            progress.pause().unwrap();
+            progress.resume().unwrap();
+            progress.cancel().unwrap();
+            progress.cancel().unwrap();
+
+            assert_eq!(
+                *spy.events.lock(),
+                vec![Event::StateChange(StateChangeEvent {
+                    id: progress.id(),
+                    old: State::Running,
+                    new: State::Canceled,
+                })]
+            );
+        }
+    }
+
+    mod rate_limit {
+        use crate::MockClock;
+
+        use super::*;
+
+        #[test]
+        fn flood() {
+            let clock = Arc::new(MockClock::new());
+            let spy = Arc::new(SpyObserver::default());
+
+            let observer = RateLimitObserver::new(
+                Arc::<SpyObserver>::clone(&spy),
+                10.0,
+                Arc::<MockClock>::clone(&clock),
+            );
+
+            let ids: Vec<_> = (0..5).map(|_| ProgressId::new_unique()).collect();
+
+            for index in 0..1000 {
+                let id = ids[index % ids.len()];
+
+                observer.observe(Event::Update(UpdateEvent { id }));
+
+                if index % 100 == 0 {
+                    observer.observe(Event::Message(MessageEvent {
+                        id,
+                        message: "test".into(),
+                        priority: PriorityLevel::Info,
+                        fields: vec![],
+                    }));
+                }
+
+                clock.advance(Duration::from_millis(1));
+            }
+
+            let (updates, messages) = {
+                let events = spy.events.lock();
+                let updates = events
+                    .iter()
+                    .filter(|event| matches!(event, Event::Update(_)))
+                    .count();
+                let messages = events
+                    .iter()
+                    .filter(|event| matches!(event, Event::Message(_)))
+                    .count();
+                (updates, messages)
+            };
+
+            // A full bucket (10) plus one second's worth of refills (10):
+            assert!(updates <= 20, "forwarded {updates} updates");
+            assert_eq!(messages, 10);
+
+            observer.flush();
+
+            let flushed = spy
+                .events
+                .lock()
+                .iter()
+                .filter(|event| matches!(event, Event::Update(_)))
+                .count()
+                - updates;
+
+            assert!(flushed <= ids.len());
+        }
+    }
+
+    mod poll {
+        use crate::{Progress, Task};
+
+        use super::*;
+
+        #[test]
+        fn drains() {
+            let observer = Arc::new(PollObserver::new());
+
+            assert_eq!(observer.poll(), vec![]);
+            assert!(!observer.latest_report_dirty());
+
+            let (progress, _reporter) =
+                Progress::new(Task::default(), Arc::<PollObserver>::clone(&observer));
+
+            progress.set_completed(1);
+            progress.message(|| "test", PriorityLevel::Info);
+            progress.set_completed(2);
+
+            let id = progress.id();
+
+            let events = observer.poll();
+
+            assert_eq!(events.len(), 3);
+            assert_eq!(events[0], Event::Update(UpdateEvent { id }));
+            assert!(matches!(events[1], Event::Message(_)));
+            assert_eq!(events[2], Event::Update(UpdateEvent { id }));
+
+            assert_eq!(observer.poll(), vec![]);
+
+            assert!(observer.latest_report_dirty());
+            assert!(!observer.latest_report_dirty());
+
+            progress.message(|| "test", PriorityLevel::Info);
+
+            assert!(!observer.latest_report_dirty());
+        }
+    }
+
+    mod remapping {
+        use crate::{AttachmentEvent, Progress, Task};
+
+        use super::*;
+
+        #[test]
+        fn consistent() {
+            let spy = Arc::new(SpyObserver::default());
+            let observer = Arc::new(RemappingObserver::new(Arc::<SpyObserver>::clone(&spy), 100));
+
+            let (parent, _reporter) =
+                Progress::new(Task::default(), Arc::<RemappingObserver>::clone(&observer));
+            let child = Progress::new_with_parent(Task::default(), &parent);
+
+            child.set_completed(1);
+            parent.set_completed(1);
+            child.set_completed(2);
+
+            let (parent_id, child_id) = (ProgressId::from_raw(100), ProgressId::from_raw(101));
+
+            assert_eq!(
+                spy.events.lock().clone(),
+                vec![
+                    Event::Attachment(AttachmentEvent {
+                        id: child_id,
+                        parent_id,
+                    }),
+                    Event::Update(UpdateEvent { id: parent_id }),
+                    Event::Update(UpdateEvent { id: child_id }),
+                    Event::Update(UpdateEvent { id: parent_id }),
+                    Event::Update(UpdateEvent { id: child_id }),
+                ]
+            );
+
+            assert_eq!(
+                observer.mapping(),
+                HashMap::from([(parent.id(), 100), (child.id(), 101)])
+            );
+            assert_eq!(observer.external_id(child.id()), Some(101));
+        }
+
+        #[test]
+        fn with_mapping() {
+            let spy = Arc::new(SpyObserver::default());
+
+            let (known, unknown) = (ProgressId::new_unique(), ProgressId::new_unique());
+
+            let observer = RemappingObserver::with_mapping(
+                Arc::<SpyObserver>::clone(&spy),
+                HashMap::from([(known, 7)]),
+                8,
+            );
+
+            observer.observe(Event::Update(UpdateEvent { id: unknown }));
+            observer.observe(Event::Detachment(DetachmentEvent { id: known }));
+            observer.observe(Event::Update(UpdateEvent { id: unknown }));
+
+            assert_eq!(
+                spy.events.lock().clone(),
+                vec![
+                    Event::Update(UpdateEvent {
+                        id: ProgressId::from_raw(8)
+                    }),
+                    Event::Detachment(DetachmentEvent {
+                        id: ProgressId::from_raw(7)
+                    }),
+                    Event::Update(UpdateEvent {
+                        id: ProgressId::from_raw(8)
+                    }),
+                ]
+            );
+        }
+
+        #[test]
+        #[cfg(target_pointer_width = "64")]
+        fn wide_external_ids() {
+            let spy = Arc::new(SpyObserver::default());
+
+            let first_external_id = u64::from(u32::MAX) + 1;
+            let observer =
+                RemappingObserver::new(Arc::<SpyObserver>::clone(&spy), first_external_id);
+
+            let id = ProgressId::new_unique();
+
+            observer.observe(Event::Update(UpdateEvent { id }));
+
+            assert_eq!(observer.external_id(id), Some(first_external_id));
+            assert_eq!(
+                spy.events.lock().clone(),
+                vec![Event::Update(UpdateEvent {
+                    id: ProgressId::from_raw(1 << 32)
+                })]
+            );
+        }
+    }
+
+    mod debouncing {
+        use crate::MockClock;
+
+        use super::*;
+
+        #[test]
+        fn collapses_updates_within_interval() {
+            let clock = Arc::new(MockClock::new());
+            let spy = Arc::new(SpyObserver::default());
+
+            let observer = DebouncingObserver::new(
+                Arc::<SpyObserver>::clone(&spy),
+                Duration::from_millis(16),
+                Arc::<MockClock>::clone(&clock),
+            );
+
+            let (id, other_id) = (ProgressId::new_unique(), ProgressId::new_unique());
+
+            let update = |id| Event::Update(UpdateEvent { id });
+            let message = Event::Message(MessageEvent {
+                id,
+                message: "test".into(),
+                priority: PriorityLevel::Info,
+                fields: vec![],
+            });
+
+            // The first update gets forwarded immediately:
+            observer.observe(update(id));
+
+            assert_eq!(spy.events.lock().clone(), vec![update(id)]);
+
+            // Updates within the interval get held back & coalesced:
+            for _ in 0..10 {
+                observer.observe(update(id));
+                observer.observe(update(other_id));
+                clock.advance(Duration::from_millis(1));
+            }
+
+            assert_eq!(spy.events.lock().len(), 1);
+
+            // Other events get forwarded immediately:
+            observer.observe(message.clone());
+
+            assert_eq!(spy.events.lock().clone(), vec![update(id), message.clone()]);
+
+            clock.advance(Duration::from_millis(6));
+
+            // The first event after the interval forwards the pending updates:
+            observer.observe(update(id));
+
+            let mut expected = vec![update(id), message];
+            let mut coalesced = vec![update(id), update(other_id)];
+            coalesced.sort_by_key(|event| match event {
+                Event::Update(UpdateEvent { id }) => *id,
+                _ => unreachable!(),
+            });
+            expected.extend(coalesced);
+
+            assert_eq!(spy.events.lock().clone(), expected);
+        }
+
+        #[test]
+        fn flush() {
+            let clock = Arc::new(MockClock::new());
+            let spy = Arc::new(SpyObserver::default());
+
+            let observer = DebouncingObserver::new(
+                Arc::<SpyObserver>::clone(&spy),
+                Duration::from_millis(16),
+                Arc::<MockClock>::clone(&clock),
+            );
+
+            let (id, detached_id) = (ProgressId::new_unique(), ProgressId::new_unique());
+
+            observer.observe(Event::Update(UpdateEvent { id }));
+            observer.observe(Event::Update(UpdateEvent { id }));
+            observer.observe(Event::Update(UpdateEvent { id: detached_id }));
+            observer.observe(Event::Detachment(DetachmentEvent { id: detached_id }));
+
+            assert_eq!(spy.events.lock().len(), 2);
+
+            observer.flush();
+
+            assert_eq!(
+                spy.events.lock().clone(),
+                vec![
+                    Event::Update(UpdateEvent { id }),
+                    Event::Detachment(DetachmentEvent { id: detached_id }),
+                    Event::Update(UpdateEvent { id }),
+                ]
+            );
+
+            observer.flush();
+
+            assert_eq!(spy.events.lock().len(), 3);
+        }
+    }
+
+    mod windowed_aggregator {
+        use crate::{MockClock, ProgressId, UpdateEvent};
+
+        use super::*;
+
+        #[test]
+        fn is_active() {
+            let (sender, receiver) = std::sync::mpsc::channel();
+
+            let observer = WindowedAggregatorObserver::new(
+                Arc::new(StdMpscObserver::from(sender)),
+                Duration::from_millis(100),
+                Arc::new(MockClock::new()),
+                |_| {},
+            );
+
+            assert!(observer.is_active());
+
+            drop(receiver);
+            observer.observe(Event::Update(UpdateEvent {
+                id: ProgressId::new_unique(),
+            }));
+
+            assert!(!observer.is_active());
+        }
+
+        #[test]
+        fn summarizes_windows() {
+            let clock = Arc::new(MockClock::new());
+            let summaries = Arc::new(Mutex::new(vec![]));
+
+            let observer = {
+                let summaries = Arc::clone(&summaries);
+                WindowedAggregatorObserver::new(
+                    Arc::new(NopObserver),
+                    Duration::from_millis(100),
+                    Arc::<MockClock>::clone(&clock),
+                    move |summary| summaries.lock().push(summary),
+                )
+            };
+
+            let id = ProgressId::new_unique();
+
+            let update = || Event::Update(UpdateEvent { id });
+            let message = |priority| {
+                Event::Message(MessageEvent {
+                    id,
+                    message: "test".into(),
+                    priority,
+                    fields: vec![],
+                })
+            };
+
+            observer.observe(update());
+            observer.observe(update());
+            observer.observe(message(PriorityLevel::Info));
+
+            clock.advance(Duration::from_millis(150));
+
+            observer.observe(update());
+            observer.observe(message(PriorityLevel::Warn));
+            observer.observe(message(PriorityLevel::Warn));
+
+            assert_eq!(summaries.lock().len(), 1);
+
+            observer.flush();
+
+            let summaries = summaries.lock();
+
+            assert_eq!(summaries.len(), 2);
+
+            assert_eq!(summaries[0].updates, 2);
+            assert_eq!(
+                summaries[0].messages,
+                BTreeMap::from([(PriorityLevel::Info, 1)])
+            );
+
+            assert_eq!(summaries[1].updates, 1);
+            assert_eq!(
+                summaries[1].messages,
+                BTreeMap::from([(PriorityLevel::Warn, 2)])
+            );
+            assert_eq!(summaries[1].start, summaries[0].end);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! A progress' portable path within its tree.
+
+/// A progress' path within its tree, as a sequence of child ordinals.
+///
+/// Unlike `ProgressId`s, which are only meaningful within the process that created them,
+/// node paths are portable, making them suitable for identifying progresses across
+/// process boundaries (e.g. in remote protocols).
+///
+/// Children are ordered by insertion (i.e. by their creation as, or attachment as,
+/// a sub-progress), i.e. an ordinal of `0` refers to a progress' earliest (still attached) child.
+///
+/// The empty path refers to the root of the tree.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodePath(Vec<usize>);
+
+impl NodePath {
+    /// Returns the path referring to the root of a tree.
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    /// Returns the path's child ordinals, starting at the root.
+    pub fn ordinals(&self) -> &[usize] {
+        &self.0
+    }
+
+    /// Returns the path's depth (i.e. its number of ordinals).
+    pub fn depth(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the path to the child with the given `ordinal`.
+    pub fn child(&self, ordinal: usize) -> Self {
+        let mut ordinals = self.0.clone();
+        ordinals.push(ordinal);
+        Self(ordinals)
+    }
+
+    /// Returns the path to the parent, or `None` if `self` refers to the root.
+    pub fn parent(&self) -> Option<Self> {
+        let (_, ordinals) = self.0.split_last()?;
+        Some(Self(ordinals.to_vec()))
+    }
+}
+
+impl From<Vec<usize>> for NodePath {
+    fn from(ordinals: Vec<usize>) -> Self {
+        Self(ordinals)
+    }
+}
+
+impl From<NodePath> for Vec<usize> {
+    fn from(path: NodePath) -> Self {
+        path.0
+    }
+}
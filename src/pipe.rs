@@ -0,0 +1,243 @@
+//! Cross-process event piping.
+//!
+//! Events get exchanged as frames, each consisting of the length
+//! of its JSON-encoded payload (as a big-endian `u32`),
+//! directly followed by the payload itself.
+
+use std::io::{self, Read, Write};
+
+use parking_lot::Mutex;
+
+use crate::{Event, Observer};
+
+/// Implementation of `Observer` writing events as length-prefixed JSON frames to a writer
+/// (e.g. a subprocess' `stdout`), to be read back by a corresponding [`PipeReader`].
+#[derive(Debug)]
+pub struct PipeObserver<W> {
+    writer: Mutex<W>,
+}
+
+impl<W> PipeObserver<W>
+where
+    W: Write,
+{
+    /// Creates an observer writing frames to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+        }
+    }
+
+    /// Consumes the observer, returning the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner()
+    }
+
+    fn write_frame(&self, event: &Event) -> io::Result<()> {
+        let payload = serde_json::to_vec(event)?;
+
+        let len = u32::try_from(payload.len())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        let mut writer = self.writer.lock();
+
+        writer.write_all(&len.to_be_bytes())?;
+        writer.write_all(&payload)?;
+        writer.flush()
+    }
+}
+
+impl<W> Observer for PipeObserver<W>
+where
+    W: Write + Send,
+{
+    fn observe(&self, event: Event) {
+        let _ = self.write_frame(&event);
+    }
+}
+
+/// Reader for the length-prefixed JSON frames written by a [`PipeObserver`]
+/// (e.g. a subprocess' `stdout`), reconstructing the original events.
+///
+/// Frames claiming a payload longer than the reader's maximum frame length
+/// (see [`PipeReader::with_max_frame_len()`]) get rejected, rather than
+/// allocating whatever a corrupt length prefix might claim.
+#[derive(Debug)]
+pub struct PipeReader<R> {
+    reader: R,
+    max_frame_len: usize,
+}
+
+impl<R> PipeReader<R>
+where
+    R: Read,
+{
+    /// The default maximum payload length of a frame, in bytes.
+    pub const DEFAULT_MAX_FRAME_LEN: usize = 16 * 1024 * 1024;
+
+    /// Creates a reader reading frames from `reader`,
+    /// with a maximum frame length of [`Self::DEFAULT_MAX_FRAME_LEN`].
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            max_frame_len: Self::DEFAULT_MAX_FRAME_LEN,
+        }
+    }
+
+    /// Sets the maximum payload length of a frame, in bytes.
+    pub fn with_max_frame_len(mut self, max_frame_len: usize) -> Self {
+        self.max_frame_len = max_frame_len;
+        self
+    }
+
+    /// Consumes the reader, returning the wrapped reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the next event, or `None` if the pipe has been closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind `io::ErrorKind::InvalidData` if the frame's
+    /// payload is longer than the maximum frame length, or isn't a valid event.
+    pub fn read_event(&mut self) -> io::Result<Option<Event>> {
+        let mut len_bytes = [0; 4];
+
+        // Distinguish a cleanly closed pipe from a truncated frame:
+        let mut read = 0;
+        while read < len_bytes.len() {
+            match self.reader.read(&mut len_bytes[read..]) {
+                Ok(0) if read == 0 => return Ok(None),
+                Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+                Ok(n) => read += n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            }
+        }
+
+        let len = u32::from_be_bytes(len_bytes) as usize;
+
+        if len > self.max_frame_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "frame length of {len} bytes exceeds maximum of {} bytes",
+                    self.max_frame_len
+                ),
+            ));
+        }
+
+        let mut payload = vec![0; len];
+        self.reader.read_exact(&mut payload)?;
+
+        let event = serde_json::from_slice(&payload)?;
+
+        Ok(Some(event))
+    }
+}
+
+impl<R> Iterator for PipeReader<R>
+where
+    R: Read,
+{
+    type Item = io::Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_event().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AttachmentEvent, DetachmentEvent, MessageEvent, PriorityLevel, ProgressId, State,
+        StateChangeEvent, UpdateEvent,
+    };
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let id = ProgressId::new_unique();
+
+        let events = vec![
+            Event::Update(UpdateEvent { id }),
+            Event::Message(MessageEvent {
+                id,
+                message: "message".into(),
+                priority: PriorityLevel::Warn,
+                fields: vec![("key".into(), "value".into())],
+            }),
+            Event::Attachment(AttachmentEvent {
+                id,
+                parent_id: ProgressId::new_unique(),
+            }),
+            Event::Detachment(DetachmentEvent { id }),
+            Event::StateChange(StateChangeEvent {
+                id,
+                old: State::Running,
+                new: State::Finished,
+            }),
+            Event::GenerationOverflow,
+        ];
+
+        let observer = PipeObserver::new(Vec::new());
+
+        for event in events.iter().cloned() {
+            observer.observe(event);
+        }
+
+        let bytes = observer.into_inner();
+
+        let reader = PipeReader::new(bytes.as_slice());
+        let actual: Vec<Event> = reader.collect::<io::Result<_>>().unwrap();
+
+        assert_eq!(actual, events);
+    }
+
+    #[test]
+    fn truncated_frame() {
+        let observer = PipeObserver::new(Vec::new());
+
+        observer.observe(Event::GenerationOverflow);
+
+        let bytes = observer.into_inner();
+
+        let mut reader = PipeReader::new(&bytes[..bytes.len() - 1]);
+
+        let err = reader.read_event().unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn oversized_frame() {
+        let observer = PipeObserver::new(Vec::new());
+
+        observer.observe(Event::GenerationOverflow);
+
+        let bytes = observer.into_inner();
+        let payload_len = bytes.len() - 4;
+
+        let mut reader = PipeReader::new(bytes.as_slice()).with_max_frame_len(payload_len - 1);
+
+        let err = reader.read_event().unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        // A corrupt length prefix doesn't result in an allocation of the claimed length:
+        let mut reader = PipeReader::new([0xFF; 4].as_slice());
+
+        let err = reader.read_event().unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let mut reader = PipeReader::new(bytes.as_slice()).with_max_frame_len(payload_len);
+
+        assert_eq!(
+            reader.read_event().unwrap(),
+            Some(Event::GenerationOverflow)
+        );
+    }
+}
@@ -7,6 +7,8 @@ use std::{
 
 use parking_lot::Once;
 
+use crate::ParsePriorityLevelError;
+
 const MIN_PRIORITY_LEVEL_KEY: &str = "SITREP_PRIO";
 
 pub(crate) fn global_min_priority_level() -> PriorityLevel {
@@ -17,13 +19,24 @@ pub(crate) fn global_min_priority_level() -> PriorityLevel {
     // Accessing the static mut is safe here, as per:
     // https://docs.rs/parking_lot/0.12.1/parking_lot/struct.Once.html#method.call_once
     unsafe {
-        ONCE.call_once(|| MIN_PRIORITY_LEVEL = PriorityLevel::from_env().unwrap());
+        // Unrecognized values get ignored, rather than panicking deep within a `message()` call:
+        ONCE.call_once(|| {
+            MIN_PRIORITY_LEVEL = PriorityLevel::from_env()
+                .ok()
+                .flatten()
+                .unwrap_or(PriorityLevel::MIN)
+        });
         MIN_PRIORITY_LEVEL
     }
 }
 
 /// A message's priority level.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 #[repr(u8)]
 pub enum PriorityLevel {
     /// A message at the "trace" level.
@@ -55,47 +68,54 @@ impl PriorityLevel {
         Self::Error,
     ];
 
-    pub(crate) fn from_env() -> Option<Self> {
-        use std::str::FromStr as _;
-
+    /// Returns the priority level specified by the `SITREP_PRIO` environment variable,
+    /// or `None` if the variable is not set.
+    ///
+    /// See [`PriorityLevel::from_str()`] for the supported values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variable is set to an unrecognized value.
+    pub fn from_env() -> Result<Option<Self>, ParsePriorityLevelError> {
         let Ok(level) = std::env::var(MIN_PRIORITY_LEVEL_KEY) else {
-            return None;
+            return Ok(None);
         };
 
-        match EnvPriorityLevel::from_str(&level) {
-            Ok(EnvPriorityLevel(min_level)) => Some(min_level),
-            Err(err) => panic!(
-                "{}",
-                format!(
-                    "Unrecognized value for env key {MIN_PRIORITY_LEVEL_KEY:?}: {value:?}",
-                    value = err.unknown
-                )
-            ),
-        }
+        Self::from_str(&level).map(Some)
     }
 }
 
-#[derive(Debug)]
-pub(crate) struct EnvPriorityLevelError {
-    #[allow(dead_code)]
-    pub(crate) unknown: String,
+impl PriorityLevel {
+    /// Returns the priority level's lowercase name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Trace => "trace",
+            Self::Debug => "debug",
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        }
+    }
 }
 
-pub(crate) struct EnvPriorityLevel(pub(crate) PriorityLevel);
+impl std::fmt::Display for PriorityLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
-impl FromStr for EnvPriorityLevel {
-    type Err = EnvPriorityLevelError;
+impl FromStr for PriorityLevel {
+    type Err = ParsePriorityLevelError;
 
+    /// Parses a priority level from one of `[trace, debug, info, warn, error]`
+    /// (case-insensitive), as the inverse of its `Display` impl.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let string = s.to_lowercase();
-        match string.as_str() {
-            "trace" => Ok(Self(PriorityLevel::Trace)),
-            "debug" => Ok(Self(PriorityLevel::Debug)),
-            "info" => Ok(Self(PriorityLevel::Info)),
-            "warn" => Ok(Self(PriorityLevel::Warn)),
-            "error" => Ok(Self(PriorityLevel::Error)),
-            _ => Err(Self::Err { unknown: string }),
-        }
+        Self::ALL
+            .into_iter()
+            .find(|level| level.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| Self::Err {
+                unknown: s.to_lowercase(),
+            })
     }
 }
 
@@ -126,9 +146,11 @@ impl TryFrom<u8> for PriorityLevelRepr {
 
 pub(crate) struct AtomicPriorityLevel(pub(crate) AtomicU8);
 
-impl From<PriorityLevel> for AtomicPriorityLevel {
-    fn from(level: PriorityLevel) -> Self {
-        Self(AtomicU8::from(level as u8))
+impl From<Option<PriorityLevel>> for AtomicPriorityLevel {
+    fn from(level: Option<PriorityLevel>) -> Self {
+        let repr = level.map(|level| level as u8).unwrap_or(0);
+
+        Self(AtomicU8::from(repr))
     }
 }
 
@@ -148,4 +170,61 @@ impl AtomicPriorityLevel {
 
         self.0.store(repr, order)
     }
+
+    pub(crate) fn swap(
+        &self,
+        level: Option<PriorityLevel>,
+        order: Ordering,
+    ) -> Option<PriorityLevel> {
+        let repr = level.map(|level| level as u8).unwrap_or(0);
+
+        match self.0.swap(repr, order) {
+            0 => None,
+            repr => Some(PriorityLevelRepr::try_from(repr).unwrap().0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(PriorityLevel::Trace.to_string(), "trace");
+        assert_eq!(PriorityLevel::Error.to_string(), "error");
+    }
+
+    #[test]
+    fn round_trip() {
+        for level in PriorityLevel::ALL {
+            assert_eq!(level.to_string().parse(), Ok(level));
+            assert_eq!(level.to_string().to_uppercase().parse(), Ok(level));
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn valid() {
+            assert_eq!("trace".parse(), Ok(PriorityLevel::Trace));
+            assert_eq!("debug".parse(), Ok(PriorityLevel::Debug));
+            assert_eq!("info".parse(), Ok(PriorityLevel::Info));
+            assert_eq!("warn".parse(), Ok(PriorityLevel::Warn));
+            assert_eq!("error".parse(), Ok(PriorityLevel::Error));
+
+            assert_eq!("WARN".parse(), Ok(PriorityLevel::Warn));
+        }
+
+        #[test]
+        fn invalid() {
+            let err = "warning".parse::<PriorityLevel>().unwrap_err();
+
+            assert_eq!(err.unknown(), "warning");
+            assert_eq!(err.to_string(), "unrecognized priority level: \"warning\"");
+
+            assert!("".parse::<PriorityLevel>().is_err());
+        }
+    }
 }
@@ -2,28 +2,48 @@
 
 use std::{
     borrow::Cow,
-    collections::HashMap,
+    collections::HashSet,
+    convert::Infallible,
+    ops::ControlFlow,
     sync::{
         atomic::{AtomicUsize, Ordering},
         Arc, Weak,
     },
+    time::{Duration, Instant},
 };
 
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
+
+use self::children::Children;
 
 use crate::{
+    aggregate::{Accumulator, Aggregate},
+    attachment::ChildAttachment,
+    builder::{OnRootDrop, ProgressOptions},
+    cancellation::CancellationToken,
+    clock::{Clock, SystemClock},
+    error::ControlError,
     event::Event,
     generation::AtomicGeneration,
+    guard::{CompletionGuard, PriorityGuard},
+    health::TreeHealth,
+    message_buffer::MessageBuffer,
+    observer::NopObserver,
+    path::NodePath,
     priority::{global_min_priority_level, AtomicPriorityLevel},
-    report::Report,
+    rate::{self, RateEstimator},
+    report::{Report, Timings},
+    sampled::Sampled,
     task::{State, Task},
-    DetachmentEvent, Generation, MessageEvent, PriorityLevel, UpdateEvent,
+    AttachmentEvent, DetachmentEvent, Generation, MessageEvent, PriorityLevel, StateChangeEvent,
+    UpdateEvent,
 };
 
 static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
 
 /// A progress' unique identifier.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProgressId(pub(crate) usize);
 
 impl Default for ProgressId {
@@ -37,6 +57,12 @@ impl ProgressId {
         Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
     }
 
+    /// Creates an identifier from a raw internal identifier value,
+    /// as previously obtained via [`ProgressId::as_raw()`].
+    pub fn from_raw(raw: usize) -> Self {
+        Self(raw)
+    }
+
     /// Returns the raw internal identifier value.
     pub fn as_raw(&self) -> usize {
         self.0
@@ -47,6 +73,27 @@ impl ProgressId {
 pub trait Observer: Send + Sync {
     /// Observes an event emitted by a progress.
     fn observe(&self, event: Event);
+
+    /// Observes a batch of events emitted by a progress, in order
+    /// (e.g. via [`Progress::update_batch()`]).
+    ///
+    /// The default implementation calls `observe(…)` for each event.
+    /// Implementations may override it to deliver the events more efficiently.
+    fn observe_batch(&self, events: Vec<Event>) {
+        for event in events {
+            self.observe(event);
+        }
+    }
+
+    /// Returns `false` if the observer is known to no longer deliver any events
+    /// (e.g. due to the receiving end of its channel having been dropped), otherwise `true`.
+    ///
+    /// Progresses skip emitting events to inactive observers.
+    ///
+    /// The default implementation always returns `true`.
+    fn is_active(&self) -> bool {
+        true
+    }
 }
 
 /// Types for generating progress reports.
@@ -56,7 +103,49 @@ pub trait Reporter: Send + Sync {
 
     /// Generates a partial progress change report for all changes since `baseline`
     /// including only sub-reports that were changed, or `None` if nothing was changed.
+    ///
+    /// The aggregate counts of each included report always reflect all of its sub-progresses,
+    /// including the unchanged ones that got pruned from its `subreports`.
+    ///
+    /// If generation tracking got disabled via
+    /// [`ProgressBuilder::track_generations()`](crate::ProgressBuilder::track_generations),
+    /// changes can't be detected, hence the full report gets returned instead.
     fn partial_report(self: &Arc<Self>, baseline: Generation) -> Option<Report>;
+
+    /// Generates the full report for a progress, except for the progresses in `collapsed`,
+    /// which get reported with their accumulative values, but without any `subreports`
+    /// (and with their `collapsed` flag set), skipping the work of generating them.
+    fn report_excluding(self: &Arc<Self>, collapsed: &HashSet<ProgressId>) -> Report;
+
+    /// Generates the full report for a progress, down to a depth of `max_depth`,
+    /// with the progresses at `max_depth` getting reported with their accumulative values,
+    /// but without any `subreports` (and with their `collapsed` flag set).
+    ///
+    /// A `max_depth` of `0` results in a report of just the progress itself.
+    fn report_to_depth(self: &Arc<Self>, max_depth: usize) -> Report;
+
+    /// Generates a summary of the health of the progress' tree
+    /// (i.e. including all of its sub-progresses).
+    fn health(self: &Arc<Self>) -> TreeHealth;
+
+    /// Generates the full report for the progress at `path` (relative to `self`),
+    /// or `None` if no such progress exists (e.g. due to the tree's structure having changed).
+    fn report_at_path(self: &Arc<Self>, path: &NodePath) -> Option<Report>;
+
+    /// Generates the full report for the progress with the given `id` within the tree
+    /// (i.e. `self` or any of its sub-progresses), or `None` if it doesn't exist.
+    fn subtree_report(self: &Arc<Self>, id: ProgressId) -> Option<Report>;
+
+    /// Returns an iterator yielding a full report every `interval`,
+    /// until the progress tree gets dropped.
+    ///
+    /// Each call to the iterator's `next()` blocks until the next tick.
+    fn sampled(self: &Arc<Self>, interval: Duration) -> Sampled<Self>
+    where
+        Self: Sized,
+    {
+        Sampled::new(Arc::downgrade(self), interval)
+    }
 }
 
 /// Types for controlling progress-tracked tasks.
@@ -65,6 +154,13 @@ pub trait Controller: Send + Sync {
     /// or `None` if it doesn't exist.
     fn get(self: &Arc<Self>, progress_id: ProgressId) -> Option<Arc<Self>>;
 
+    /// Returns the sub-progress with the given raw identifier
+    /// (as obtained via [`ProgressId::as_raw()`]) within the tree,
+    /// or `None` if it doesn't exist.
+    fn get_by_raw(self: &Arc<Self>, raw: usize) -> Option<Arc<Self>> {
+        self.get(ProgressId::from_raw(raw))
+    }
+
     /// Returns `true` if the task is cancelable, otherwise `false`.
     fn is_cancelable(self: &Arc<Self>) -> bool;
 
@@ -79,15 +175,104 @@ pub trait Controller: Send + Sync {
 
     /// Sets the state of the corresponding `Progress` task
     /// (and all its running sub-tasks) to `Paused`, recursively.
-    fn pause(self: &Arc<Self>);
+    ///
+    /// # Errors
+    ///
+    /// Returns `ControlError::NotPausable` for the first encountered task that
+    /// is not pausable, leaving any not yet visited sub-tasks unchanged.
+    fn pause(self: &Arc<Self>) -> Result<(), ControlError>;
 
     /// Sets the state of the corresponding `Progress` task
     /// (and all its paused sub-tasks) to `Running`, recursively.
-    fn resume(self: &Arc<Self>);
+    ///
+    /// # Errors
+    ///
+    /// Returns `ControlError::NotPausable` for the first encountered task that
+    /// is not pausable, leaving any not yet visited sub-tasks unchanged.
+    fn resume(self: &Arc<Self>) -> Result<(), ControlError>;
 
     /// Sets the state of the corresponding `Progress` task
     /// (and all its running/paused sub-tasks) to `Canceled`, recursively.
-    fn cancel(self: &Arc<Self>);
+    ///
+    /// # Errors
+    ///
+    /// Returns `ControlError::NotCancelable` for the first encountered task that
+    /// is not cancelable, leaving any not yet visited sub-tasks unchanged.
+    fn cancel(self: &Arc<Self>) -> Result<(), ControlError>;
+
+    /// Sets the state of the corresponding `Progress` task (but not its sub-tasks)
+    /// to `Paused`, if it is running.
+    ///
+    /// Sub-tasks keep running, and keep contributing to the progress' aggregate counts,
+    /// while the progress' own `Report::state` reports `Paused`. Use
+    /// [`Report::aggregate_state()`] or [`Report::state_counts()`] for taking the
+    /// (mixed) states of the whole subtree into account.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ControlError::NotPausable` if the task is not pausable.
+    fn pause_self(self: &Arc<Self>) -> Result<(), ControlError>;
+
+    /// Sets the state of the corresponding `Progress` task (but not its sub-tasks)
+    /// to `Running`, if it is paused.
+    ///
+    /// See [`Controller::pause_self()`] for how mixed states get reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ControlError::NotPausable` if the task is not pausable.
+    fn resume_self(self: &Arc<Self>) -> Result<(), ControlError>;
+
+    /// Sets the state of the corresponding `Progress` task (but not its sub-tasks)
+    /// to `Canceled`, if it is running or paused.
+    ///
+    /// See [`Controller::pause_self()`] for how mixed states get reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ControlError::NotCancelable` if the task is not cancelable.
+    fn cancel_self(self: &Arc<Self>) -> Result<(), ControlError>;
+
+    /// Pauses the sub-progress with the given `id` within the tree
+    /// (and all its running sub-tasks), leaving the rest of the tree unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ControlError::NotFound` if no such sub-progress exists,
+    /// otherwise see [`Controller::pause()`].
+    fn pause_subtree(self: &Arc<Self>, id: ProgressId) -> Result<(), ControlError> {
+        self.get(id).ok_or(ControlError::NotFound)?.pause()
+    }
+
+    /// Resumes the sub-progress with the given `id` within the tree
+    /// (and all its paused sub-tasks), leaving the rest of the tree unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ControlError::NotFound` if no such sub-progress exists,
+    /// otherwise see [`Controller::resume()`].
+    fn resume_subtree(self: &Arc<Self>, id: ProgressId) -> Result<(), ControlError> {
+        self.get(id).ok_or(ControlError::NotFound)?.resume()
+    }
+
+    /// Cancels the sub-progress with the given `id` within the tree
+    /// (and all its running/paused sub-tasks), leaving the rest of the tree unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ControlError::NotFound` if no such sub-progress exists,
+    /// otherwise see [`Controller::cancel()`].
+    fn cancel_subtree(self: &Arc<Self>, id: ProgressId) -> Result<(), ControlError> {
+        self.get(id).ok_or(ControlError::NotFound)?.cancel()
+    }
+
+    /// Sets the state of the corresponding `Progress` task (but not its sub-tasks)
+    /// back to `Running`, while setting its completed unit count to `completed`,
+    /// emitting a single update event.
+    ///
+    /// This allows for resuming a paused, canceled, or finished task
+    /// while keeping its already completed units of work.
+    fn resume_from(self: &Arc<Self>, completed: usize);
 }
 
 /// The progress' state.
@@ -98,6 +283,116 @@ struct ProgressState {
     ///
     /// All progresses in a progress tree share the same observer.
     observer: Arc<dyn Observer>,
+    /// The most recently emitted messages.
+    messages: MessageBuffer,
+    /// The policy to apply when getting dropped as a root.
+    on_root_drop: OnRootDrop,
+    /// Whether or not to automatically finish the task upon completing all of its units.
+    auto_complete: bool,
+    /// The clock used for time measurements.
+    clock: Arc<dyn Clock>,
+    /// The instant at which the progress got created.
+    started_at: Instant,
+    /// The instant at which the task's units of work got last updated.
+    last_update: Instant,
+    /// The accumulated duration of the task's pauses, as of its last update.
+    paused_duration_at_last_update: Duration,
+    /// The instant at which the task got paused, if it currently is.
+    paused_since: Option<Instant>,
+    /// The accumulated duration of the task's past pauses.
+    paused_duration: Duration,
+    /// The task's completion rate estimator.
+    rate: RateEstimator,
+    /// The callbacks to invoke upon the task's state changing.
+    state_change_callbacks: Vec<StateChangeCallback>,
+    /// The highest priority level among the emitted messages, if any.
+    highest_priority_seen: Option<PriorityLevel>,
+}
+
+/// A callback invoked with the new state upon a task's state changing.
+type StateChangeCallback = Arc<dyn Fn(State) + Send + Sync>;
+
+impl ProgressState {
+    /// Applies `update_task` to the task, returning its old and new state,
+    /// or `None` if `update_task` returned `false`.
+    fn apply_update(
+        &mut self,
+        update_task: impl FnOnce(&mut Task) -> bool,
+    ) -> Option<(State, State)> {
+        let old_state = self.task.state;
+        let old_completed = self.task.completed;
+        let old_aggregate = self.task.effective_aggregate();
+
+        if !update_task(&mut self.task) {
+            return None;
+        }
+
+        if self.auto_complete {
+            let task = &mut self.task;
+
+            if task.state == State::Running && task.total > 0 && task.completed >= task.total {
+                task.state = State::Finished;
+            }
+        }
+
+        if self.task.effective_aggregate() != old_aggregate {
+            self.last_update = self.clock.now();
+            self.paused_duration_at_last_update = self.paused_duration;
+        }
+
+        if self.task.completed != old_completed {
+            let now = self.clock.now();
+            self.rate.sample(now, self.task.completed);
+        }
+
+        if self.task.state != old_state {
+            self.track_pauses(old_state, self.task.state);
+        }
+
+        Some((old_state, self.task.state))
+    }
+
+    fn timings(&self) -> Timings {
+        let now = self.clock.now();
+
+        let elapsed = now.saturating_duration_since(self.started_at);
+
+        let ongoing_pause = self.paused_since.map_or(Duration::ZERO, |paused_since| {
+            now.saturating_duration_since(paused_since)
+        });
+        let active_elapsed = elapsed.saturating_sub(self.paused_duration + ongoing_pause);
+
+        let paused_since_last_update = (self.paused_duration + ongoing_pause)
+            .saturating_sub(self.paused_duration_at_last_update);
+        let since_last_update = now
+            .saturating_duration_since(self.last_update)
+            .saturating_sub(paused_since_last_update);
+
+        let units_per_second = self.rate.units_per_second();
+
+        Timings {
+            elapsed,
+            active_elapsed,
+            since_last_update,
+            units_per_second,
+        }
+    }
+
+    /// Keeps track of the time spent paused, upon the task's state changing.
+    fn track_pauses(&mut self, old: State, new: State) {
+        match (old == State::Paused, new == State::Paused) {
+            (false, true) => {
+                self.paused_since = Some(self.clock.now());
+            }
+            (true, false) => {
+                if let Some(paused_since) = self.paused_since.take() {
+                    let pause = self.clock.now().saturating_duration_since(paused_since);
+                    self.paused_duration += pause;
+                }
+            }
+            _ => {}
+        }
+    }
 }
 
 /// The progress' atomic state.
@@ -106,6 +401,8 @@ struct ProgressAtomicState {
     min_priority_level: AtomicPriorityLevel,
     /// The task's current generation.
     last_change: AtomicGeneration,
+    /// The number of times the task has been canceled.
+    cancellations: AtomicUsize,
 }
 
 /// The progress' relationships.
@@ -113,7 +410,16 @@ struct ProgressRelationships {
     /// The progress' parent progress, if there is one.
     parent: Weak<Progress>,
     /// The progress' child progresses, if there are any.
-    children: HashMap<ProgressId, Arc<Progress>>,
+    children: Children,
+}
+
+/// The partial report of a progress.
+enum PartialReport {
+    /// The progress changed, hence is part of the report.
+    Changed(Report),
+    /// The progress is unchanged, hence gets pruned from the report,
+    /// but still has to contribute its aggregate (and weight) to its parent's.
+    Unchanged(Aggregate, f64),
 }
 
 /// A progress.
@@ -126,6 +432,11 @@ pub struct Progress {
     state: RwLock<ProgressState>,
     /// The progress' atomic state.
     atomic_state: ProgressAtomicState,
+    /// Whether or not the progress keeps track of its `last_change`.
+    track_generations: bool,
+    /// The token signaled upon the task getting canceled,
+    /// replaced by a fresh one upon the task leaving its canceled state.
+    cancellation_token: Mutex<CancellationToken>,
 }
 
 impl Progress {
@@ -138,12 +449,22 @@ impl Progress {
         task: Task,
         observer: Arc<dyn Observer>,
     ) -> (Arc<Self>, Weak<impl Reporter + Controller>) {
-        let parent = Weak::new();
-
-        let progress = Self::new_impl(task, parent, observer);
-        let reporter = Arc::downgrade(&progress);
+        Self::new_root_impl(
+            ProgressId::new_unique(),
+            task,
+            observer,
+            ProgressOptions::default(),
+        )
+    }
 
-        (progress, reporter)
+    /// Creates a progress object for the given `task`, without observing any of its events.
+    ///
+    /// Reports can still be obtained via the returned `Reporter`, but since no events
+    /// get emitted (nor observed) there is nothing notifying the receiving end of any changes.
+    ///
+    /// Use this to explicitly opt out of observation, rather than passing a `NopObserver` to `new()`.
+    pub fn new_silent(task: Task) -> (Arc<Self>, Weak<impl Reporter + Controller>) {
+        Self::new(task, Arc::new(NopObserver))
     }
 
     /// Creates a progress object for the given `task` as a sub-progress of `parent`,
@@ -152,12 +473,73 @@ impl Progress {
     /// Returned are the progress itself, as well as a `Reporter`
     /// which is used on the receiving end of the channel for obtaining reports.
     pub fn new_with_parent(task: Task, parent: &Arc<Self>) -> Arc<Self> {
-        let parent_state = parent.state.read();
+        Self::new_child_impl(
+            ProgressId::new_unique(),
+            task,
+            parent,
+            ProgressOptions::default(),
+        )
+    }
+
+    /// Creates a progress object with a caller-chosen `id` for the given `task`,
+    /// emitting relevant events to `observer`.
+    ///
+    /// Explicit identifiers are not drawn from the global identifier counter,
+    /// so it is up to the caller to ensure their uniqueness.
+    ///
+    /// Only available with the `test-utils` feature.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn new_with_explicit_id(
+        id: ProgressId,
+        task: Task,
+        observer: Arc<dyn Observer>,
+    ) -> (Arc<Self>, Weak<impl Reporter + Controller>) {
+        Self::new_root_impl(id, task, observer, ProgressOptions::default())
+    }
+
+    /// Creates a progress object with a caller-chosen `id` for the given `task`
+    /// as a sub-progress of `parent`.
+    ///
+    /// Explicit identifiers are not drawn from the global identifier counter,
+    /// so it is up to the caller to ensure their uniqueness.
+    ///
+    /// Only available with the `test-utils` feature.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn new_with_parent_and_explicit_id(
+        id: ProgressId,
+        task: Task,
+        parent: &Arc<Self>,
+    ) -> Arc<Self> {
+        debug_assert!(
+            parent.root().get(id).is_none(),
+            "id {id:?} already in use within the tree"
+        );
+
+        Self::new_child_impl(id, task, parent, ProgressOptions::default())
+    }
+
+    pub(crate) fn new_root_impl(
+        id: ProgressId,
+        task: Task,
+        observer: Arc<dyn Observer>,
+        options: ProgressOptions,
+    ) -> (Arc<Self>, Weak<impl Reporter + Controller>) {
+        let progress = Self::new_impl(id, task, None, observer, options);
+        let reporter = Arc::downgrade(&progress);
 
+        (progress, reporter)
+    }
+
+    pub(crate) fn new_child_impl(
+        id: ProgressId,
+        task: Task,
+        parent: &Arc<Self>,
+        options: ProgressOptions,
+    ) -> Arc<Self> {
         // Children share the observer of their parent:
-        let observer = parent_state.observer.clone();
+        let observer = parent.state.read().observer.clone();
 
-        let child = Self::new_impl(task, Arc::downgrade(parent), observer);
+        let child = Self::new_impl(id, task, Some(parent), observer, options);
 
         parent
             .relationships
@@ -167,26 +549,89 @@ impl Progress {
 
         let parent_state = parent.state.read();
 
+        parent.emit_attachment_event(&*parent_state.observer, child.id);
         parent.emit_update_event(&*parent_state.observer, parent.id);
 
         child
     }
 
-    fn new_impl(task: Task, parent: Weak<Self>, observer: Arc<dyn Observer>) -> Arc<Self> {
-        let id = ProgressId::new_unique();
-        let parent = parent;
-        let children = HashMap::new();
+    fn new_impl(
+        id: ProgressId,
+        task: Task,
+        parent: Option<&Arc<Self>>,
+        observer: Arc<dyn Observer>,
+        options: ProgressOptions,
+    ) -> Arc<Self> {
+        // Options that weren't provided explicitly get inherited from the parent:
+        let message_buffer_capacity = options
+            .message_buffer
+            .or_else(|| parent.map(|parent| parent.message_buffer_capacity()))
+            .unwrap_or(0);
 
-        let relationships = RwLock::new(ProgressRelationships { parent, children });
+        let on_root_drop = options.on_root_drop.unwrap_or_default();
+
+        let track_generations = options
+            .track_generations
+            .or_else(|| parent.map(|parent| parent.track_generations))
+            .unwrap_or(true);
 
-        let state = RwLock::new(ProgressState { task, observer });
+        let clock = options
+            .clock
+            .or_else(|| parent.map(|parent| Arc::clone(&parent.state.read().clock)))
+            .unwrap_or_else(|| Arc::new(SystemClock));
 
-        let min_priority_level = AtomicPriorityLevel::from(PriorityLevel::MIN);
+        let started_at = clock.now();
+        let paused_since = (task.state == State::Paused).then_some(started_at);
+
+        let rate_smoothing = options
+            .rate_smoothing
+            .or_else(|| parent.map(|parent| parent.state.read().rate.smoothing()))
+            .unwrap_or(rate::DEFAULT_SMOOTHING);
+
+        let rate = RateEstimator::new(rate_smoothing, started_at, task.completed);
+
+        let cancellation_token = CancellationToken::default();
+
+        if task.state == State::Canceled {
+            cancellation_token.cancel();
+        }
+
+        let cancellation_token = Mutex::new(cancellation_token);
+
+        let parent = parent.map(Arc::downgrade).unwrap_or_default();
+        let children = Children::default();
+
+        let relationships = RwLock::new(ProgressRelationships { parent, children });
+
+        let messages = MessageBuffer::new(message_buffer_capacity);
+
+        let state = RwLock::new(ProgressState {
+            task,
+            observer,
+            messages,
+            on_root_drop,
+            auto_complete: false,
+            clock,
+            started_at,
+            last_update: started_at,
+            paused_duration_at_last_update: Duration::ZERO,
+            paused_since,
+            paused_duration: Duration::ZERO,
+            rate,
+            state_change_callbacks: vec![],
+            highest_priority_seen: None,
+        });
+
+        // Fall back to the global minimum priority level, unless overridden:
+        let min_priority_level = AtomicPriorityLevel::from(None);
         let last_change = AtomicGeneration::from(Generation::MIN);
 
+        let cancellations = AtomicUsize::new(0);
+
         let atomic_state = ProgressAtomicState {
             min_priority_level,
             last_change,
+            cancellations,
         };
 
         Arc::new(Self {
@@ -194,23 +639,51 @@ impl Progress {
             relationships,
             state,
             atomic_state,
+            track_generations,
+            cancellation_token,
         })
     }
 
+    #[cfg(any(test, feature = "test-utils"))]
+    fn root(self: &Arc<Self>) -> Arc<Self> {
+        let mut root = Arc::clone(self);
+
+        while let Some(parent) = root.parent() {
+            root = parent;
+        }
+
+        root
+    }
+
     /// Attaches `child` to `self`, returning the `child's` own and now no longer used `Observer`.
+    ///
+    /// The attached sub-tree adopts the observer of `self`'s tree.
+    ///
+    /// If `child` is still attached to a parent it gets detached from it first,
+    /// emitting a detachment event (followed by an update event) for its former parent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `child` is `self`, or one of its ancestors, as that would result in a cycle.
     pub fn attach_child(self: &Arc<Self>, child: &Arc<Self>) -> Arc<dyn Observer> {
+        assert!(
+            self.id != child.id && !self.is_descendant_of(child),
+            "cannot attach a progress to itself or one of its sub-progresses"
+        );
+
+        child.leave_parent();
+
         let child_last_change = child.atomic_state.last_change.load(Ordering::Relaxed);
         self.atomic_state
             .last_change
             .fetch_max(child_last_change, Ordering::Relaxed);
 
-        let observer = {
-            let parent_state = self.state.read();
-            let mut child_state = child.state.write();
+        let parent_observer = self.state.read().observer.clone();
 
-            // Make sure the child uses the parent's observer from now on:
-            std::mem::replace(&mut child_state.observer, parent_state.observer.clone())
-        };
+        // Make sure the child's sub-tree uses the parent's observer from now on:
+        let observer = child.adopt_observer(parent_observer);
+
+        child.relationships.write().parent = Arc::downgrade(self);
 
         self.relationships
             .write()
@@ -219,11 +692,49 @@ impl Progress {
 
         self.bump_last_change();
 
-        self.emit_update_event(&*self.state.read().observer, self.id);
+        let state = self.state.read();
+
+        self.emit_attachment_event(&*state.observer, child.id);
+        self.emit_update_event(&*state.observer, self.id);
 
         observer
     }
 
+    /// Attaches `child` to `self`, returning a guard holding on to the `child's` own `Observer`,
+    /// which gets restored upon detaching the `child` via the guard.
+    pub fn attach_child_scoped(self: &Arc<Self>, child: &Arc<Self>) -> ChildAttachment {
+        let observer = self.attach_child(child);
+
+        ChildAttachment::new(self, child, observer)
+    }
+
+    /// Creates a sub-progress of `self` for the given `task`, passing it to `f`,
+    /// and detaches it again (with a `NopObserver`) once `f` returns (or unwinds).
+    ///
+    /// Does not detach the sub-progress if it already got detached
+    /// (or moved to another parent) from within `f`.
+    pub fn with_child<R>(self: &Arc<Self>, task: Task, f: impl FnOnce(&Arc<Self>) -> R) -> R {
+        struct DetachOnDrop<'a> {
+            parent: &'a Arc<Progress>,
+            child: Arc<Progress>,
+        }
+
+        impl Drop for DetachOnDrop<'_> {
+            fn drop(&mut self) {
+                if self.parent.child(self.child.id).is_some() {
+                    self.parent.detach_child(&self.child, Arc::new(NopObserver));
+                }
+            }
+        }
+
+        let guard = DetachOnDrop {
+            parent: self,
+            child: Self::new_with_parent(task, self),
+        };
+
+        f(&guard.child)
+    }
+
     /// Detaches `child` from `self`, giving it a new `observer`.
     pub fn detach_child(self: &Arc<Self>, child: &Arc<Self>, observer: Arc<dyn Observer>) {
         assert!(
@@ -249,16 +760,135 @@ impl Progress {
 
         let state = parent.state.read();
 
-        parent.emit_removed_event(&*state.observer, self.id);
+        parent.emit_detachment_event(&*state.observer, self.id);
+        parent.emit_update_event(&*state.observer, parent.id);
+    }
+
+    /// Moves `self` (and its sub-progresses) from its current parent (if any) to `new_parent`,
+    /// without severing it from a parent in between.
+    ///
+    /// The moved sub-tree adopts the observer of `new_parent`'s tree.
+    ///
+    /// Emits a detachment event for `self` (followed by an update event) for the former
+    /// parent (if any), followed by an attachment event for `self` (followed by an update event)
+    /// for `new_parent`, each to the observer of the respective parent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_parent` is `self`, or one of its sub-progresses,
+    /// as that would result in a cycle.
+    pub fn reparent(self: &Arc<Self>, new_parent: &Arc<Self>) {
+        assert!(
+            self.id != new_parent.id && !new_parent.is_descendant_of(self),
+            "cannot reparent a progress under itself or one of its sub-progresses"
+        );
+
+        if let Some(old_parent) = self.parent() {
+            if old_parent.id == new_parent.id {
+                return;
+            }
+        }
+
+        self.leave_parent();
+
+        let self_last_change = self.atomic_state.last_change.load(Ordering::Relaxed);
+        new_parent
+            .atomic_state
+            .last_change
+            .fetch_max(self_last_change, Ordering::Relaxed);
+
+        let observer = new_parent.state.read().observer.clone();
+
+        self.adopt_observer(observer);
+
+        self.relationships.write().parent = Arc::downgrade(new_parent);
+
+        new_parent
+            .relationships
+            .write()
+            .children
+            .insert(self.id, Arc::clone(self));
+
+        new_parent.bump_last_change();
+
+        let state = new_parent.state.read();
+
+        new_parent.emit_attachment_event(&*state.observer, self.id);
+        new_parent.emit_update_event(&*state.observer, new_parent.id);
+    }
+
+    /// Removes `self` from its parent's children (if any), emitting a detachment event
+    /// (followed by an update event) for the former parent.
+    ///
+    /// The caller is responsible for updating the parent (and observer) of `self`.
+    fn leave_parent(self: &Arc<Self>) {
+        let Some(parent) = self.parent() else {
+            return;
+        };
+
+        parent.relationships.write().children.remove(&self.id);
+
+        parent.bump_last_change();
+
+        let state = parent.state.read();
+
+        parent.emit_detachment_event(&*state.observer, self.id);
         parent.emit_update_event(&*state.observer, parent.id);
     }
 
+    /// Replaces the observer of `self` and all of its sub-progresses with `observer`,
+    /// returning the former observer of `self`.
+    fn adopt_observer(self: &Arc<Self>, observer: Arc<dyn Observer>) -> Arc<dyn Observer> {
+        let former_observer = std::mem::replace(&mut self.state.write().observer, observer.clone());
+
+        let mut subtree: Vec<_> = self.children().collect();
+
+        while let Some(progress) = subtree.pop() {
+            progress.state.write().observer = observer.clone();
+            subtree.extend(progress.children());
+        }
+
+        former_observer
+    }
+
+    /// Detaches all children from `self`, giving each of them
+    /// a new observer obtained from `observer_factory`.
+    ///
+    /// Unlike detaching each child individually this only emits a single update event
+    /// for `self` (following one detachment event per child).
+    pub fn detach_all_children(
+        self: &Arc<Self>,
+        observer_factory: impl Fn(ProgressId) -> Arc<dyn Observer>,
+    ) {
+        let children: Vec<_> = self.relationships.write().children.drain().collect();
+
+        if children.is_empty() {
+            return;
+        }
+
+        for child in &children {
+            child.state.write().observer = observer_factory(child.id);
+            child.relationships.write().parent = Weak::new();
+        }
+
+        self.bump_last_change();
+
+        let state = self.state.read();
+
+        for child in &children {
+            self.emit_detachment_event(&*state.observer, child.id);
+        }
+
+        self.emit_update_event(&*state.observer, self.id);
+    }
+
     /// Returns the progress' parent, or `None` if `self` has no parent.
     pub fn parent(self: &Arc<Self>) -> Option<Arc<Self>> {
         self.relationships.read().parent.upgrade()
     }
 
-    /// Returns the progress' children.
+    /// Returns the progress' children, in order of insertion
+    /// (i.e. of their creation as, or attachment as, a sub-progress).
     pub fn children(self: &Arc<Self>) -> impl Iterator<Item = Arc<Self>> {
         self.relationships
             .read()
@@ -269,32 +899,115 @@ impl Progress {
             .into_iter()
     }
 
-    /// Returns the child with the given `id` within the tree, or `None` if it doesn't exist.
-    pub fn child(self: &Arc<Self>, id: ProgressId) -> Option<Arc<Progress>> {
-        self.relationships.read().children.get(&id).cloned()
-    }
-
-    /// Returns the associated unique ID.
-    pub fn id(&self) -> ProgressId {
-        self.id
+    /// Performs a depth-first (pre-order) traversal of the progress' subtree,
+    /// calling `f` on the progress itself, followed by each of its descendants.
+    ///
+    /// Unlike [`Progress::snapshot`] this doesn't allocate a report for the whole subtree,
+    /// making it a cheap alternative for when only a few values are of interest.
+    ///
+    /// No locks are held while `f` is being called, so it is safe for `f`
+    /// to call methods on (or attach/detach children to/from) the visited progress.
+    pub fn visit(self: &Arc<Self>, f: &mut impl FnMut(&Arc<Self>)) {
+        let result = self.try_walk(|progress| {
+            f(progress);
+            Ok::<(), Infallible>(())
+        });
+
+        result.unwrap_or_else(|never| match never {})
     }
 
-    /// Emits a message event with a priority level of `MessageLevel::Error`.
+    /// Generates the full report for the progress,
+    /// without requiring the `Reporter` trait to be imported.
     ///
-    /// See [`message()`](method@message) for more info (e.g. filtering).
-    pub fn error<T>(self: &Arc<Self>, message: impl FnOnce() -> T)
-    where
-        T: Into<Cow<'static, str>>,
-    {
-        self.message(message, PriorityLevel::Error);
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use sitrep::{NopObserver, Progress, Task};
+    /// let (progress, _reporter) = Progress::new(Task::default().total(10), Arc::new(NopObserver));
+    ///
+    /// progress.set_completed(5);
+    ///
+    /// assert_eq!(progress.snapshot().fraction, 0.5);
+    /// ```
+    pub fn snapshot(self: &Arc<Self>) -> Report {
+        Reporter::report(self)
     }
 
-    /// Emits a message event with a priority level of `MessageLevel::Warn`.
+    /// Returns the progress' path, relative to its tree's root,
+    /// or `None` if the progress (or any of its ancestors) got detached
+    /// from its parent (e.g. on another thread) while determining the path.
     ///
-    /// See [`message()`](method@message) for more info (e.g. filtering).
-    pub fn warn<T>(self: &Arc<Self>, message: impl FnOnce() -> T)
-    where
-        T: Into<Cow<'static, str>>,
+    /// See [`NodePath`] for more info.
+    pub fn path(self: &Arc<Self>) -> Option<NodePath> {
+        let mut ordinals = vec![];
+
+        let mut progress = Arc::clone(self);
+
+        while let Some(parent) = progress.parent() {
+            let ordinal = parent
+                .relationships
+                .read()
+                .children
+                .keys()
+                .position(|id| *id == progress.id)?;
+
+            ordinals.push(ordinal);
+
+            progress = parent;
+        }
+
+        ordinals.reverse();
+
+        Some(NodePath::from(ordinals))
+    }
+
+    /// Returns the child with the given `id` within the tree, or `None` if it doesn't exist.
+    pub fn child(self: &Arc<Self>, id: ProgressId) -> Option<Arc<Progress>> {
+        self.relationships.read().children.get(&id).cloned()
+    }
+
+    /// Returns `true` if `self` is a (direct or indirect) descendant of `ancestor`,
+    /// otherwise `false` (including if `self` is `ancestor`).
+    pub fn is_descendant_of(self: &Arc<Self>, ancestor: &Arc<Self>) -> bool {
+        let mut current = self.parent();
+
+        while let Some(progress) = current {
+            if progress.id == ancestor.id {
+                return true;
+            }
+
+            current = progress.parent();
+        }
+
+        false
+    }
+
+    /// Returns `true` if `self` is a (direct or indirect) ancestor of `descendant`,
+    /// otherwise `false` (including if `self` is `descendant`).
+    pub fn is_ancestor_of(self: &Arc<Self>, descendant: &Arc<Self>) -> bool {
+        descendant.is_descendant_of(self)
+    }
+
+    /// Returns the associated unique ID.
+    pub fn id(&self) -> ProgressId {
+        self.id
+    }
+
+    /// Emits a message event with a priority level of `MessageLevel::Error`.
+    ///
+    /// See [`message()`](method@message) for more info (e.g. filtering).
+    pub fn error<T>(self: &Arc<Self>, message: impl FnOnce() -> T)
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        self.message(message, PriorityLevel::Error);
+    }
+
+    /// Emits a message event with a priority level of `MessageLevel::Warn`.
+    ///
+    /// See [`message()`](method@message) for more info (e.g. filtering).
+    pub fn warn<T>(self: &Arc<Self>, message: impl FnOnce() -> T)
+    where
+        T: Into<Cow<'static, str>>,
     {
         self.message(message, PriorityLevel::Warn);
     }
@@ -335,21 +1048,125 @@ impl Progress {
     ///
     /// By default, `Progress` emits all message events with a minimum priority level of `trace`.
     ///
-    /// The `SITREP_PRIORITY` environment variable controls filtering with the syntax:
+    /// The `SITREP_PRIO` environment variable controls filtering with the syntax:
     ///
     /// ```terminal
-    /// SITREP_PRIORITY=[level]
+    /// SITREP_PRIO=[level]
     /// ```
     pub fn message<T>(self: &Arc<Self>, message: impl FnOnce() -> T, level: PriorityLevel)
     where
         T: Into<Cow<'static, str>>,
+    {
+        self.message_with(message, level, std::iter::empty::<(&str, &str)>());
+    }
+
+    /// Emits a message event with a priority level of `level`,
+    /// with structured key-value `fields` attached to it.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use sitrep::{NopObserver, PriorityLevel, Progress, Task};
+    /// let (progress, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+    ///
+    /// progress.message_with(
+    ///     || "Downloaded file",
+    ///     PriorityLevel::Info,
+    ///     [("file", "x.rs".to_owned()), ("bytes", 1024.to_string())],
+    /// );
+    /// ```
+    ///
+    /// See [`Progress::message()`] for more info.
+    pub fn message_with<T, K, V>(
+        self: &Arc<Self>,
+        message: impl FnOnce() -> T,
+        level: PriorityLevel,
+        fields: impl IntoIterator<Item = (K, V)>,
+    ) where
+        T: Into<Cow<'static, str>>,
+        K: Into<Cow<'static, str>>,
+        V: Into<Cow<'static, str>>,
     {
         if level < self.min_priority_level() {
             return;
         }
 
+        let message = message().into();
+        let fields: Vec<_> = fields
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect();
+
+        if self.state.read().messages.is_enabled() {
+            self.state.write().messages.push(MessageEvent {
+                id: self.id(),
+                message: message.clone(),
+                priority: level,
+                fields: fields.clone(),
+            });
+        }
+
+        if self.state.read().highest_priority_seen < Some(level) {
+            {
+                let mut state = self.state.write();
+                state.highest_priority_seen = state.highest_priority_seen.max(Some(level));
+            }
+
+            self.bump_last_change();
+        }
+
+        let state = self.state.read();
+        self.emit_message_event(&*state.observer, message, level, fields);
+    }
+
+    /// Resets the highest priority level among the emitted messages,
+    /// as reported via [`Report::highest_priority_seen`] (e.g. once the user
+    /// has acknowledged a severity badge shown for the progress).
+    ///
+    /// Only affects the progress itself, not its sub-progresses.
+    pub fn clear_messages_seen(self: &Arc<Self>) {
+        self.state.write().highest_priority_seen = None;
+
+        self.bump_last_change();
+
         let state = self.state.read();
-        self.emit_message_event(&*state.observer, message().into(), level);
+        self.emit_update_event(&*state.observer, self.id);
+    }
+
+    /// Returns the capacity of the progress' message buffer.
+    ///
+    /// A capacity of `0` means that no messages are being retained.
+    ///
+    /// See [`ProgressBuilder::message_buffer()`](crate::ProgressBuilder::message_buffer) for more info.
+    pub fn message_buffer_capacity(&self) -> usize {
+        self.state.read().messages.capacity()
+    }
+
+    /// Sets the capacity of the progress' message history (i.e. its message buffer)
+    /// to `capacity`, discarding the oldest retained messages that no longer fit.
+    ///
+    /// A capacity of `0` disables the buffer. Unlike
+    /// [`ProgressBuilder::message_buffer()`](crate::ProgressBuilder::message_buffer)
+    /// this only affects the progress itself, not its (existing) sub-progresses.
+    pub fn set_message_history_capacity(&self, capacity: usize) {
+        self.state.write().messages.set_capacity(capacity);
+    }
+
+    /// Returns the most recently emitted messages retained
+    /// in the progress' message buffer, in the order they were emitted.
+    ///
+    /// See [`ProgressBuilder::message_buffer()`](crate::ProgressBuilder::message_buffer) for more info.
+    pub fn recent_messages(&self) -> Vec<MessageEvent> {
+        self.state.read().messages.to_vec()
+    }
+
+    /// Replays the messages retained in the progress' message buffer
+    /// to `observer` (e.g. a late-joining frontend), in the order they were emitted.
+    ///
+    /// See [`ProgressBuilder::message_buffer()`](crate::ProgressBuilder::message_buffer) for more info.
+    pub fn replay_messages(&self, observer: &dyn Observer) {
+        for message in self.recent_messages() {
+            observer.observe(Event::Message(message));
+        }
     }
 
     /// Overrides the global minimum priority level.
@@ -360,10 +1177,10 @@ impl Progress {
     ///
     /// # Global environment override
     ///
-    /// The `SITREP_PRIORITY` environment variable allows for overriding with the syntax:
+    /// The `SITREP_PRIO` environment variable allows for overriding with the syntax:
     ///
     /// ```terminal
-    /// SITREP_PRIORITY=[level]
+    /// SITREP_PRIO=[level]
     /// ```
     ///
     /// where `level` is one of `[trace, debug, info, warn, error]`.
@@ -373,12 +1190,28 @@ impl Progress {
             .store(level, Ordering::Relaxed)
     }
 
+    /// Overrides the global minimum priority level with `level`
+    /// until the returned guard gets dropped, upon which the previous
+    /// override (or lack thereof) gets restored.
+    ///
+    /// Guards are expected to be dropped in the reverse order of their creation.
+    ///
+    /// See [`Progress::set_min_priority_level()`] for more info.
+    pub fn with_min_priority_level(&self, level: PriorityLevel) -> PriorityGuard<'_> {
+        let previous = self
+            .atomic_state
+            .min_priority_level
+            .swap(Some(level), Ordering::Relaxed);
+
+        PriorityGuard::new(self, previous)
+    }
+
     /// Returns the effective minimum priority level.
     ///
     /// If no local level has been overridden it returns
     /// a fallback in the following order of precedence:
     ///
-    /// - environment (i.e. `SITREP_PRIORITY=[level]`)
+    /// - environment (i.e. `SITREP_PRIO=[level]`)
     /// - default (i.e. `PriorityLevel::Trace`)
     pub fn min_priority_level(&self) -> PriorityLevel {
         self.atomic_state
@@ -403,7 +1236,26 @@ impl Progress {
         self.state.read().task.label.clone()
     }
 
-    /// Increments the task's completed unit count by `1`.
+    /// Returns the non-empty labels of the progress' ancestors (from the root down)
+    /// and of the progress itself, joined by `separator` (e.g. `"Parent > Child"`).
+    ///
+    /// Progresses without a label get skipped.
+    pub fn label_path(self: &Arc<Self>, separator: &str) -> String {
+        let mut labels = vec![];
+        let mut current = Some(Arc::clone(self));
+
+        while let Some(progress) = current {
+            labels.extend(progress.label().filter(|label| !label.is_empty()));
+            current = progress.parent();
+        }
+
+        labels.reverse();
+
+        labels.join(separator)
+    }
+
+    /// Increments the task's completed unit count by `1`,
+    /// saturating at `usize::MAX` rather than overflowing.
     ///
     /// # Performance
     ///
@@ -411,10 +1263,11 @@ impl Progress {
     /// individual calls to setters as those would emit one event per setter call,
     /// while `progress.update(|task| … )` only emits a single event at the very end.
     pub fn increment_completed(self: &Arc<Self>) {
-        self.update(|task| task.completed += 1);
+        self.increment_completed_by(1);
     }
 
-    /// Increments the task's completed unit count by `increment`.
+    /// Increments the task's completed unit count by `increment`,
+    /// saturating at `usize::MAX` rather than overflowing.
     ///
     /// # Performance
     ///
@@ -422,7 +1275,92 @@ impl Progress {
     /// individual calls to setters as those would emit one event per setter call,
     /// while `progress.update(|task| … )` only emits a single event at the very end.
     pub fn increment_completed_by(self: &Arc<Self>, increment: usize) {
-        self.update(|task| task.completed += increment);
+        self.update(|task| task.completed = task.completed.saturating_add(increment));
+    }
+
+    /// Returns an iterator that drives the progress from the fallible `iter`,
+    /// incrementing the task's completed unit count per item,
+    /// as well as its failed unit count and emitting an error message per `Err(…)` item.
+    ///
+    /// The items get passed through unchanged.
+    pub fn drive_results<T, E>(
+        self: &Arc<Self>,
+        iter: impl IntoIterator<Item = Result<T, E>>,
+    ) -> impl Iterator<Item = Result<T, E>>
+    where
+        E: std::fmt::Display,
+    {
+        let progress = Arc::clone(self);
+
+        iter.into_iter().inspect(move |result| match result {
+            Ok(_) => progress.increment_completed(),
+            Err(error) => {
+                progress.update(|task| {
+                    task.completed = task.completed.saturating_add(1);
+                    task.failures = task.failures.saturating_add(1);
+                });
+                progress.message(|| error.to_string(), PriorityLevel::Error);
+            }
+        })
+    }
+
+    /// Returns the task's failed unit count.
+    pub fn failures(self: &Arc<Self>) -> usize {
+        self.state.read().task.failures
+    }
+
+    /// Registers a callback to be invoked with the task's new state
+    /// whenever its state actually changes (e.g. via `pause()`, `resume()`, or `cancel()`,
+    /// including the cancellation of a sub-progress due to its parent getting canceled).
+    ///
+    /// The callback gets invoked on the thread causing the state change,
+    /// before the corresponding `Event::StateChange` gets emitted.
+    ///
+    /// The callback gets invoked without the progress' state being locked,
+    /// hence it may access (e.g. update) the progress itself.
+    pub fn on_state_change(&self, callback: impl Fn(State) + Send + Sync + 'static) {
+        self.state
+            .write()
+            .state_change_callbacks
+            .push(Arc::new(callback));
+    }
+
+    /// Returns a token for observing the task's cancellation without polling its state
+    /// (e.g. for waking a worker blocked on I/O).
+    ///
+    /// ```
+    /// # use std::{sync::Arc, thread};
+    /// # use sitrep::{Controller, NopObserver, Progress, Task};
+    /// let (progress, _reporter) = Progress::new(Task::default().cancelable(true), Arc::new(NopObserver));
+    ///
+    /// let token = progress.cancellation_token();
+    /// let worker = thread::spawn(move || token.wait());
+    ///
+    /// progress.cancel().unwrap();
+    /// worker.join().unwrap();
+    /// ```
+    ///
+    /// Once signaled, a token remains signaled. If the task leaves its canceled state
+    /// afterwards (e.g. via [`reset()`](Self::reset) or [`resume_from()`](Controller::resume_from)),
+    /// the progress hands out fresh (i.e. non-signaled) tokens from then on.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation_token.lock().clone()
+    }
+
+    /// Returns a guard that increments the task's completed unit count by `1` when dropped.
+    ///
+    /// If the task gets canceled before the guard is dropped (or the guard gets
+    /// [forgotten](CompletionGuard::forget)), then the guard's work is considered
+    /// abandoned and dropping it has no effect.
+    pub fn unit_guard(self: &Arc<Self>) -> CompletionGuard {
+        let cancellations = self.atomic_state.cancellations.load(Ordering::Relaxed);
+
+        CompletionGuard::new(Arc::downgrade(self), cancellations)
+    }
+
+    pub(crate) fn was_canceled_since(&self, cancellations: usize) -> bool {
+        self.atomic_state.cancellations.load(Ordering::Relaxed) != cancellations
+            || self.state.read().task.state == State::Canceled
     }
 
     /// Sets the task's completed unit count to `completed`.
@@ -436,6 +1374,22 @@ impl Progress {
         self.update(|task| task.completed = completed);
     }
 
+    /// Sets the task's completed unit count to `completed`,
+    /// but only if it differs from the current count.
+    ///
+    /// Returns `true` if the count got changed (and an event got emitted), otherwise `false`.
+    pub fn set_completed_checked(self: &Arc<Self>, completed: usize) -> bool {
+        self.update_if(|task| {
+            if task.completed == completed {
+                return false;
+            }
+
+            task.completed = completed;
+
+            true
+        })
+    }
+
     /// Returns the task's completed unit count.
     pub fn completed(self: &Arc<Self>) -> usize {
         self.state.read().task.completed
@@ -454,11 +1408,60 @@ impl Progress {
         self.update(|task| task.total = total);
     }
 
+    /// Sets the task's completed weighted unit count to `weight_completed`.
+    ///
+    /// See [`Task::weight_total()`](crate::Task::weight_total) for more info on weighted units.
+    ///
+    /// # Performance
+    ///
+    /// When making multiple changes prefer to use the `update(…)` method over multiple
+    /// individual calls to setters as those would emit one event per setter call,
+    /// while `progress.update(|task| … )` only emits a single event at the very end.
+    pub fn set_weight_completed(self: &Arc<Self>, weight_completed: f64) {
+        self.update(|task| task.weight_completed = Some(weight_completed));
+    }
+
+    /// Sets the task's total weighted unit count to `weight_total`.
+    ///
+    /// See [`Task::weight_total()`](crate::Task::weight_total) for more info on weighted units.
+    ///
+    /// # Performance
+    ///
+    /// When making multiple changes prefer to use the `update(…)` method over multiple
+    /// individual calls to setters as those would emit one event per setter call,
+    /// while `progress.update(|task| … )` only emits a single event at the very end.
+    pub fn set_weight_total(self: &Arc<Self>, weight_total: f64) {
+        self.update(|task| task.weight_total = Some(weight_total));
+    }
+
     /// Returns the task's total unit count.
     pub fn total(self: &Arc<Self>) -> usize {
         self.state.read().task.total
     }
 
+    /// Returns `true` if the task's own progress is indeterminate
+    /// (i.e. it has neither any completed, nor any total units, discrete or weighted),
+    /// otherwise `false`.
+    ///
+    /// Unlike [`Report::is_indeterminate`] this only considers the progress' own task,
+    /// not the tasks of its sub-progresses, hence requires no report to be generated.
+    pub fn is_indeterminate(self: &Arc<Self>) -> bool {
+        self.state
+            .read()
+            .task
+            .effective_aggregate()
+            .is_indeterminate()
+    }
+
+    /// Returns the accumulative `(completed, total)` unit counts
+    /// of the progress and all of its sub-progresses, recursively.
+    ///
+    /// The returned counts are the same as those of the progress' [`Report`],
+    /// yet are considerably cheaper to obtain than generating a full report.
+    pub fn aggregate_discrete(self: &Arc<Self>) -> (usize, usize) {
+        self.aggregate().clamped_discrete()
+    }
+
     /// Sets the task's state to `state`.
     ///
     /// # Performance
@@ -470,11 +1473,98 @@ impl Progress {
         self.update(|task| task.state = state);
     }
 
+    /// Resets the task for another run, emitting a single update event.
+    ///
+    /// Resets the task's completed unit counts and failed unit count to `0`
+    /// and its state to `Running`, while keeping its label, total unit counts
+    /// and all other settings.
+    pub fn reset(self: &Arc<Self>) {
+        self.update(|task| {
+            task.completed = 0;
+            task.failures = 0;
+            task.state = State::Running;
+
+            if task.weight_completed.is_some() {
+                task.weight_completed = Some(0.0);
+            }
+        });
+    }
+
+    /// Replaces the task with `task`, emitting a single update event.
+    pub fn reset_with(self: &Arc<Self>, task: Task) {
+        self.update(|old_task| *old_task = task);
+    }
+
+    /// Marks the task as `Finished`, raising its completed unit count to its total.
+    ///
+    /// Finished tasks are terminal, i.e. they can no longer be paused, resumed,
+    /// or canceled (with such attempts being no-ops).
+    pub fn complete(self: &Arc<Self>) {
+        self.update(|task| {
+            task.state = State::Finished;
+            task.completed = task.completed.max(task.total);
+        });
+    }
+
     /// Returns the task's state.
     pub fn state(self: &Arc<Self>) -> State {
         self.state.read().task.state
     }
 
+    /// Sets the task's relative weight.
+    ///
+    /// See [`Task::weight()`] for more info.
+    ///
+    /// # Performance
+    ///
+    /// When making multiple changes prefer to use the `update(…)` method over multiple
+    /// individual calls to setters as those would emit one event per setter call,
+    /// while `progress.update(|task| … )` only emits a single event at the very end.
+    pub fn set_weight(self: &Arc<Self>, weight: f64) {
+        self.update(|task| task.weight = weight);
+    }
+
+    /// Sets the task's display unit scale.
+    ///
+    /// See [`Task::unit_scale()`] for more info.
+    ///
+    /// # Performance
+    ///
+    /// When making multiple changes prefer to use the `update(…)` method over multiple
+    /// individual calls to setters as those would emit one event per setter call,
+    /// while `progress.update(|task| … )` only emits a single event at the very end.
+    pub fn set_unit_scale(self: &Arc<Self>, unit_scale: f64) {
+        self.update(|task| task.unit_scale = unit_scale);
+    }
+
+    /// Sets the task's display unit suffix.
+    ///
+    /// # Performance
+    ///
+    /// When making multiple changes prefer to use the `update(…)` method over multiple
+    /// individual calls to setters as those would emit one event per setter call,
+    /// while `progress.update(|task| … )` only emits a single event at the very end.
+    pub fn set_unit_suffix(self: &Arc<Self>, unit_suffix: impl Into<Option<Cow<'static, str>>>) {
+        self.update(|task| task.unit_suffix = unit_suffix.into());
+    }
+
+    /// Sets the policy to apply to the progress' surviving sub-progresses
+    /// when it gets dropped as the root of its tree.
+    ///
+    /// See [`ProgressBuilder::on_root_drop()`](crate::ProgressBuilder::on_root_drop) for more info.
+    pub fn set_on_root_drop(self: &Arc<Self>, on_root_drop: OnRootDrop) {
+        self.state.write().on_root_drop = on_root_drop;
+    }
+
+    /// Sets whether or not the task should automatically transition from `Running`
+    /// to `Finished` upon an update leaving it with `completed >= total` (and `total > 0`),
+    /// emitting a corresponding state change event.
+    ///
+    /// Auto-completion only applies to subsequent updates and is disabled by default.
+    pub fn set_auto_complete(self: &Arc<Self>, auto_complete: bool) {
+        self.state.write().auto_complete = auto_complete;
+    }
+
     /// Sets whether or not the task is cancelable.
     ///
     /// # Performance
@@ -505,48 +1595,190 @@ impl Progress {
     /// individual calls to setters as those would emit one event per setter call,
     /// while `progress.update(|task| … )` only emits a single event at the very end.
     pub fn update(self: &Arc<Self>, update_task: impl FnOnce(&mut Task)) {
-        update_task(&mut self.state.write().task);
+        self.update_if(|task| {
+            update_task(task);
+            true
+        });
+    }
+
+    /// Updates the associated task, emitting a corresponding event afterwards
+    /// if `update_task` returned `true`, returning the very same value.
+    ///
+    /// If the update changed the task's state, then a state change event
+    /// gets emitted after the update event.
+    fn update_if(self: &Arc<Self>, update_task: impl FnOnce(&mut Task) -> bool) -> bool {
+        let Some((old_state, new_state)) = self.state.write().apply_update(update_task) else {
+            return false;
+        };
 
         self.bump_last_change();
 
         self.emit_update_event(&*self.state.read().observer, self.id);
+
+        if old_state != new_state {
+            self.did_change_state(old_state, new_state);
+        }
+
+        true
+    }
+
+    /// Applies each of `updates` to the associated task, in order,
+    /// emitting the corresponding events afterwards as a single batch
+    /// via [`Observer::observe_batch()`].
+    ///
+    /// Just like for `update(…)` each update results in an update event,
+    /// followed by a state change event, if it changed the task's state.
+    /// Callbacks registered via [`Progress::on_state_change()`]
+    /// get invoked before the batch gets emitted.
+    pub fn update_batch<F>(self: &Arc<Self>, updates: impl IntoIterator<Item = F>)
+    where
+        F: FnOnce(&mut Task),
+    {
+        let transitions: Vec<_> = {
+            let state = &mut *self.state.write();
+
+            updates
+                .into_iter()
+                .filter_map(|update_task| {
+                    state.apply_update(|task| {
+                        update_task(task);
+                        true
+                    })
+                })
+                .collect()
+        };
+
+        if transitions.is_empty() {
+            return;
+        }
+
+        self.bump_last_change();
+
+        let (observer, callbacks) = self.observer_and_state_change_callbacks();
+
+        let mut events = Vec::with_capacity(transitions.len());
+
+        for (old, new) in transitions {
+            events.push(Event::Update(UpdateEvent { id: self.id }));
+
+            if old != new {
+                self.run_state_change_hooks(&callbacks, old, new);
+                events.push(Event::StateChange(StateChangeEvent {
+                    id: self.id,
+                    old,
+                    new,
+                }));
+            }
+        }
+
+        if observer.is_active() {
+            observer.observe_batch(events);
+        }
+    }
+
+    /// Transitions the task's state to the one returned by `transition`
+    /// (if any), emitting a state change event afterwards.
+    ///
+    /// Returns `true` if a transition happened, otherwise `false`.
+    fn transition_state(self: &Arc<Self>, transition: impl FnOnce(State) -> Option<State>) -> bool {
+        let (old_state, new_state) = {
+            let state = &mut *self.state.write();
+            let old_state = state.task.state;
+
+            let Some(new_state) = transition(old_state) else {
+                return false;
+            };
+
+            state.task.state = new_state;
+            state.track_pauses(old_state, new_state);
+
+            (old_state, new_state)
+        };
+
+        self.did_change_state(old_state, new_state);
+
+        true
+    }
+
+    /// Returns the observer and state change callbacks, for use without holding
+    /// the state's lock (as callbacks may access the progress themselves).
+    fn observer_and_state_change_callbacks(&self) -> (Arc<dyn Observer>, Vec<StateChangeCallback>) {
+        let state = self.state.read();
+
+        (
+            Arc::clone(&state.observer),
+            state.state_change_callbacks.clone(),
+        )
+    }
+
+    fn did_change_state(self: &Arc<Self>, old: State, new: State) {
+        let (observer, callbacks) = self.observer_and_state_change_callbacks();
+
+        self.run_state_change_hooks(&callbacks, old, new);
+        self.emit_state_change_event(&*observer, old, new);
+    }
+
+    fn run_state_change_hooks(
+        self: &Arc<Self>,
+        callbacks: &[StateChangeCallback],
+        old: State,
+        new: State,
+    ) {
+        if new == State::Canceled {
+            self.cancellation_token.lock().cancel();
+        } else if old == State::Canceled {
+            // The previous token remains signaled for any of its holders:
+            *self.cancellation_token.lock() = CancellationToken::default();
+        }
+
+        for callback in callbacks {
+            callback(new);
+        }
     }
 
     fn bump_last_change(self: &Arc<Self>) -> (Generation, bool) {
-        if let Some(parent) = self.relationships.read().parent.upgrade() {
-            let (last_change, overflow) = parent.bump_last_change();
+        if !self.track_generations {
+            let last_change = self.atomic_state.last_change.load(Ordering::Relaxed);
+            return (last_change, false);
+        }
 
-            let prev_last_change = self
-                .atomic_state
-                .last_change
-                .swap(last_change, Ordering::Relaxed);
+        // Walk up to the root iteratively (rather than recursively),
+        // to support trees of arbitrary depth without overflowing the stack:
+        let mut descendants = vec![];
+        let mut root = Arc::clone(self);
 
-            debug_assert_eq!(prev_last_change >= last_change, overflow);
+        while let Some(parent) = root.parent() {
+            descendants.push(std::mem::replace(&mut root, parent));
+        }
 
-            (last_change, overflow)
-        } else {
-            const INCREMENT: usize = 1;
+        const INCREMENT: usize = 1;
 
-            let prev_last_change = self
-                .atomic_state
-                .last_change
-                .fetch_add(INCREMENT, Ordering::Relaxed);
+        let prev_last_change = root
+            .atomic_state
+            .last_change
+            .fetch_add(INCREMENT, Ordering::Relaxed);
 
-            // Since `fetch_add()` returns the previous value we need to perform an
-            // equivalent wrapping add to obtain the new (i.e. stored) `last_change`:
-            let (last_change, overflow) = prev_last_change.add(INCREMENT);
+        // Since `fetch_add()` returns the previous value we need to perform an
+        // equivalent wrapping add to obtain the new (i.e. stored) `last_change`:
+        let (last_change, overflow) = prev_last_change.add(INCREMENT);
 
-            debug_assert_eq!(prev_last_change >= last_change, overflow);
+        debug_assert_eq!(prev_last_change >= last_change, overflow);
 
-            if overflow {
-                self.state
-                    .read()
-                    .observer
-                    .observe(Event::GenerationOverflow);
-            }
+        if overflow {
+            root.emit_generation_overflow_event(&*root.state.read().observer);
+        }
 
-            (last_change, overflow)
+        // Propagate the root's new `last_change` back down to `self`:
+        for progress in descendants.iter().rev() {
+            let prev_last_change = progress
+                .atomic_state
+                .last_change
+                .swap(last_change, Ordering::Relaxed);
+
+            debug_assert_eq!(prev_last_change >= last_change, overflow);
         }
+
+        (last_change, overflow)
     }
 
     fn emit_message_event(
@@ -554,62 +1786,267 @@ impl Progress {
         observer: &dyn Observer,
         message: Cow<'static, str>,
         priority: PriorityLevel,
+        fields: Vec<(Cow<'static, str>, Cow<'static, str>)>,
     ) {
+        if !observer.is_active() {
+            return;
+        }
+
         observer.observe(Event::Message(MessageEvent {
             id: self.id(),
             message,
             priority,
+            fields,
         }));
     }
 
     fn emit_update_event(self: &Arc<Self>, observer: &dyn Observer, id: ProgressId) {
+        if !observer.is_active() {
+            return;
+        }
+
         observer.observe(Event::Update(UpdateEvent { id }));
     }
 
-    fn emit_removed_event(self: &Arc<Self>, observer: &dyn Observer, id: ProgressId) {
+    fn emit_state_change_event(self: &Arc<Self>, observer: &dyn Observer, old: State, new: State) {
+        if !observer.is_active() {
+            return;
+        }
+
+        observer.observe(Event::StateChange(StateChangeEvent {
+            id: self.id,
+            old,
+            new,
+        }));
+    }
+
+    fn emit_generation_overflow_event(self: &Arc<Self>, observer: &dyn Observer) {
+        if !observer.is_active() {
+            return;
+        }
+
+        observer.observe(Event::GenerationOverflow);
+    }
+
+    fn emit_attachment_event(self: &Arc<Self>, observer: &dyn Observer, id: ProgressId) {
+        if !observer.is_active() {
+            return;
+        }
+
+        observer.observe(Event::Attachment(AttachmentEvent {
+            id,
+            parent_id: self.id,
+        }));
+    }
+
+    fn emit_detachment_event(self: &Arc<Self>, observer: &dyn Observer, id: ProgressId) {
+        if !observer.is_active() {
+            return;
+        }
+
         observer.observe(Event::Detachment(DetachmentEvent { id }));
     }
 
     fn report(&self) -> Report {
+        self.report_impl(&|_, _| false)
+    }
+
+    fn report_impl(&self, is_collapsed: &dyn Fn(&Self, usize) -> bool) -> Report {
+        self.fold(
+            |progress, depth| progress.report_enter(depth, is_collapsed),
+            |progress, last_change, subreports| {
+                let sub_aggregates: Vec<_> = subreports
+                    .iter()
+                    .map(|report| (report.aggregate(), report.weight))
+                    .collect();
+
+                progress.report_exit(last_change, subreports, sub_aggregates)
+            },
+        )
+    }
+
+    /// Returns the collapsed report, if `self` is collapsed,
+    /// or its `last_change`, otherwise.
+    fn report_enter(
+        &self,
+        depth: usize,
+        is_collapsed: &dyn Fn(&Self, usize) -> bool,
+    ) -> ControlFlow<Report, Generation> {
         let last_change = self.atomic_state.last_change.load(Ordering::Relaxed);
 
-        let subreports: Vec<_> = self
-            .relationships
-            .read()
-            .children
-            .values()
-            .map(|progress| progress.report())
-            .collect();
+        if !is_collapsed(self, depth) {
+            return ControlFlow::Continue(last_change);
+        }
 
-        let progress_id = self.id;
+        let aggregate = self.aggregate();
+        let state = self.state.read();
 
-        let (own_completed, own_total, label, state) = {
-            let task = &self.state.read().task;
-            let (completed, total) = task.effective_discrete();
-            let label = task.label.clone();
-            let state = task.state;
-            (completed, total, label, state)
-        };
+        ControlFlow::Break(Report::new_collapsed(
+            self.id,
+            &state.task,
+            state.timings(),
+            state.highest_priority_seen,
+            aggregate,
+            last_change,
+        ))
+    }
 
-        let (completed, total): (usize, usize) = subreports
-            .iter()
-            .map(|report| report.discrete())
-            .fold((own_completed, own_total), |sum, item| {
-                (sum.0.saturating_add(item.0), sum.1.saturating_add(item.1))
-            });
+    /// Returns the report, given the reports of its (non-pruned) children,
+    /// as well as the aggregates (and weights) of all of its children.
+    fn report_exit(
+        &self,
+        last_change: Generation,
+        subreports: Vec<Report>,
+        sub_aggregates: Vec<(Aggregate, f64)>,
+    ) -> Report {
+        let state = self.state.read();
+
+        let aggregate = Self::accumulate(state.task.effective_aggregate(), sub_aggregates);
 
         Report::new(
-            progress_id,
-            label,
-            completed,
-            total,
-            state,
+            self.id,
+            &state.task,
+            state.timings(),
+            state.highest_priority_seen,
+            aggregate,
             subreports,
             last_change,
         )
     }
 }
 
+impl Progress {
+    /// Returns the progress' children, in order of insertion.
+    fn ordered_children(&self) -> Vec<Arc<Self>> {
+        self.relationships
+            .read()
+            .children
+            .values()
+            .map(Arc::clone)
+            .collect()
+    }
+
+    /// Folds the progress and its sub-progresses in depth-first post-order.
+    ///
+    /// For each progress `enter` gets called with its depth (relative to `self`),
+    /// returning either its result (skipping its sub-progresses) or an intermediate
+    /// value, which then gets passed to `exit` together with its sub-progresses' results.
+    ///
+    /// The traversal is iterative (rather than recursive),
+    /// to support trees of arbitrary depth without overflowing the stack.
+    fn fold<S, T>(
+        &self,
+        mut enter: impl FnMut(&Self, usize) -> ControlFlow<T, S>,
+        mut exit: impl FnMut(&Self, S, Vec<T>) -> T,
+    ) -> T {
+        /// A partially folded progress, still awaiting the results of its `children`.
+        struct Frame<S, T> {
+            /// The progress, or `None` for `self`.
+            progress: Option<Arc<Progress>>,
+            depth: usize,
+            value: S,
+            children: std::vec::IntoIter<Arc<Progress>>,
+            results: Vec<T>,
+        }
+
+        let mut stack: Vec<Frame<S, T>> = vec![];
+
+        match enter(self, 0) {
+            ControlFlow::Break(result) => return result,
+            ControlFlow::Continue(value) => stack.push(Frame {
+                progress: None,
+                depth: 0,
+                value,
+                children: self.ordered_children().into_iter(),
+                results: vec![],
+            }),
+        }
+
+        loop {
+            let frame = stack.last_mut().expect("non-empty stack");
+
+            if let Some(child) = frame.children.next() {
+                let depth = frame.depth + 1;
+
+                match enter(&child, depth) {
+                    ControlFlow::Break(result) => frame.results.push(result),
+                    ControlFlow::Continue(value) => stack.push(Frame {
+                        children: child.ordered_children().into_iter(),
+                        progress: Some(child),
+                        depth,
+                        value,
+                        results: vec![],
+                    }),
+                }
+
+                continue;
+            }
+
+            let frame = stack.pop().expect("non-empty stack");
+
+            let progress = frame.progress.as_deref().unwrap_or(self);
+            let result = exit(progress, frame.value, frame.results);
+
+            match stack.last_mut() {
+                Some(parent) => parent.results.push(result),
+                None => return result,
+            }
+        }
+    }
+
+    /// Calls `f` for the progress and its sub-progresses in depth-first pre-order,
+    /// stopping at the first error returned by `f`.
+    ///
+    /// The traversal is iterative (rather than recursive),
+    /// to support trees of arbitrary depth without overflowing the stack.
+    fn try_walk<E>(
+        self: &Arc<Self>,
+        mut f: impl FnMut(&Arc<Self>) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut stack = vec![Arc::clone(self)];
+
+        while let Some(progress) = stack.pop() {
+            f(&progress)?;
+
+            stack.extend(progress.ordered_children().into_iter().rev());
+        }
+
+        Ok(())
+    }
+
+    fn aggregate(&self) -> Aggregate {
+        let (aggregate, _weight) = self.aggregate_with_weight();
+
+        aggregate
+    }
+
+    fn aggregate_with_weight(&self) -> (Aggregate, f64) {
+        self.fold(
+            |_, _| ControlFlow::Continue(()),
+            |progress, (), sub_aggregates| progress.aggregate_exit(sub_aggregates),
+        )
+    }
+
+    /// Returns the aggregate (and weight), given the aggregates (and weights) of its children.
+    fn aggregate_exit(&self, sub_aggregates: Vec<(Aggregate, f64)>) -> (Aggregate, f64) {
+        let task = &self.state.read().task;
+
+        let aggregate = Self::accumulate(task.effective_aggregate(), sub_aggregates);
+
+        (aggregate, task.weight)
+    }
+
+    fn accumulate(own_aggregate: Aggregate, sub_aggregates: Vec<(Aggregate, f64)>) -> Aggregate {
+        let mut accumulator = Accumulator::new(own_aggregate);
+
+        for (aggregate, weight) in sub_aggregates {
+            accumulator.add(aggregate, weight);
+        }
+
+        accumulator.finish()
+    }
+}
+
 impl std::fmt::Debug for Progress {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let relationships = self.relationships.read();
@@ -634,74 +2071,117 @@ impl Reporter for Progress {
     }
 
     fn partial_report(self: &Arc<Self>, generation: Generation) -> Option<Report> {
-        let last_change = self.atomic_state.last_change.load(Ordering::Relaxed);
+        if !self.track_generations {
+            // Without generation tracking there is no telling what changed:
+            return Some(self.report());
+        }
 
-        if last_change <= generation {
-            return None;
+        let partial_report = self.fold(
+            |progress, _| {
+                let last_change = progress.atomic_state.last_change.load(Ordering::Relaxed);
+
+                if last_change <= generation {
+                    let (aggregate, weight) = progress.aggregate_with_weight();
+                    return ControlFlow::Break(PartialReport::Unchanged(aggregate, weight));
+                }
+
+                ControlFlow::Continue(last_change)
+            },
+            |progress, last_change, partial_reports| {
+                let mut subreports: Vec<Report> = vec![];
+                let mut sub_aggregates: Vec<(Aggregate, f64)> = vec![];
+
+                for partial_report in partial_reports {
+                    match partial_report {
+                        PartialReport::Changed(subreport) => {
+                            sub_aggregates.push((subreport.aggregate(), subreport.weight));
+                            subreports.push(subreport);
+                        }
+                        PartialReport::Unchanged(aggregate, weight) => {
+                            sub_aggregates.push((aggregate, weight));
+                        }
+                    }
+                }
+
+                PartialReport::Changed(progress.report_exit(
+                    last_change,
+                    subreports,
+                    sub_aggregates,
+                ))
+            },
+        );
+
+        match partial_report {
+            PartialReport::Changed(report) => Some(report),
+            PartialReport::Unchanged(..) => None,
         }
+    }
 
-        let mut subreports: Vec<Report> = vec![];
-        let mut sub_completed: usize = 0;
-        let mut sub_total: usize = 0;
+    fn report_excluding(self: &Arc<Self>, collapsed: &HashSet<ProgressId>) -> Report {
+        self.report_impl(&|progress, _| collapsed.contains(&progress.id))
+    }
 
-        for child in self.relationships.read().children.values() {
-            let (completed, total) = if let Some(subreport) = child.partial_report(generation) {
-                let discrete = subreport.discrete();
-                subreports.push(subreport);
-                discrete
-            } else {
-                child.state.read().task.effective_discrete()
-            };
+    fn report_to_depth(self: &Arc<Self>, max_depth: usize) -> Report {
+        self.report_impl(&|_, depth| depth >= max_depth)
+    }
 
-            sub_completed = sub_completed.saturating_add(completed);
-            sub_total = sub_total.saturating_add(total);
-        }
+    fn report_at_path(self: &Arc<Self>, path: &NodePath) -> Option<Report> {
+        let mut progress = Arc::clone(self);
 
-        if subreports.is_empty() && last_change <= generation {
-            return None;
+        for &ordinal in path.ordinals() {
+            progress = progress.ordered_children().into_iter().nth(ordinal)?;
         }
 
-        let progress_id = self.id;
+        Some(progress.report())
+    }
 
-        let (own_completed, own_total, label, state) = {
-            let task = &self.state.read().task;
-            let (completed, total) = task.effective_discrete();
-            let label = task.label.clone();
-            let state = task.state;
-            (completed, total, label, state)
-        };
+    fn subtree_report(self: &Arc<Self>, id: ProgressId) -> Option<Report> {
+        self.get(id).map(|progress| progress.report())
+    }
 
-        let (completed, total) = (own_completed + sub_completed, own_total + sub_total);
+    fn health(self: &Arc<Self>) -> TreeHealth {
+        let mut health = TreeHealth::default();
 
-        Some(Report::new(
-            progress_id,
-            label,
-            completed,
-            total,
-            state,
-            subreports,
-            last_change,
-        ))
+        let (aggregate, _weight) = self.fold(
+            |progress, _| {
+                let state = progress.state.read();
+
+                health.max_priority = health.max_priority.max(state.highest_priority_seen);
+
+                let count = match state.task.state {
+                    State::Running => &mut health.running,
+                    State::Paused => &mut health.paused,
+                    State::Finished => &mut health.finished,
+                    State::Canceled => &mut health.canceled,
+                };
+
+                *count += 1;
+
+                health.nodes += 1;
+
+                ControlFlow::Continue(())
+            },
+            |progress, (), sub_aggregates| progress.aggregate_exit(sub_aggregates),
+        );
+
+        health.fraction = aggregate.fraction;
+
+        health
     }
 }
 
 impl Controller for Progress {
     fn get(self: &Arc<Self>, progress_id: ProgressId) -> Option<Arc<Self>> {
-        if self.id == progress_id {
-            return Some(Arc::clone(self));
-        }
-
-        let children = &self.relationships.read().children;
-
-        let child = children.get(&progress_id);
+        let result = self.try_walk(|progress| {
+            if progress.id == progress_id {
+                // Bail out early, as soon as the progress got found:
+                return Err(Arc::clone(progress));
+            }
 
-        if child.is_some() {
-            return child.cloned();
-        }
+            Ok(())
+        });
 
-        children
-            .values()
-            .find_map(|progress| progress.get(progress_id))
+        result.err()
     }
 
     fn is_cancelable(self: &Arc<Self>) -> bool {
@@ -720,55 +2200,132 @@ impl Controller for Progress {
         self.state.read().task.state == State::Paused
     }
 
-    fn pause(self: &Arc<Self>) {
-        if !self.is_pausable() {
-            panic!("not pausable");
-        }
-
-        let guard = &mut self.state.write();
+    fn pause(self: &Arc<Self>) -> Result<(), ControlError> {
+        self.try_walk(|progress| progress.pause_self())
+    }
 
-        if guard.task.state == State::Running {
-            guard.task.state = State::Paused;
-        }
+    fn resume(self: &Arc<Self>) -> Result<(), ControlError> {
+        self.try_walk(|progress| progress.resume_self())
+    }
 
-        for child in self.relationships.read().children.values() {
-            child.pause();
-        }
+    fn cancel(self: &Arc<Self>) -> Result<(), ControlError> {
+        self.try_walk(|progress| progress.cancel_self())
     }
 
-    fn resume(self: &Arc<Self>) {
+    fn pause_self(self: &Arc<Self>) -> Result<(), ControlError> {
         if !self.is_pausable() {
-            panic!("not resumable");
+            return Err(ControlError::NotPausable);
         }
 
-        let guard = &mut self.state.write();
+        self.transition_state(|state| (state == State::Running).then_some(State::Paused));
 
-        if guard.task.state == State::Paused {
-            guard.task.state = State::Running;
-        }
+        Ok(())
+    }
 
-        for child in self.relationships.read().children.values() {
-            child.resume();
+    fn resume_self(self: &Arc<Self>) -> Result<(), ControlError> {
+        if !self.is_pausable() {
+            return Err(ControlError::NotPausable);
         }
+
+        self.transition_state(|state| (state == State::Paused).then_some(State::Running));
+
+        Ok(())
     }
 
-    fn cancel(self: &Arc<Self>) {
+    fn cancel_self(self: &Arc<Self>) -> Result<(), ControlError> {
         if !self.is_cancelable() {
-            panic!("not cancelable");
+            return Err(ControlError::NotCancelable);
         }
 
-        let guard = &mut self.state.write();
+        self.transition_to_canceled();
+
+        Ok(())
+    }
 
-        if [State::Paused, State::Running].contains(&guard.task.state) {
-            guard.task.state = State::Canceled;
+    fn resume_from(self: &Arc<Self>, completed: usize) {
+        self.update(|task| {
+            task.state = State::Running;
+            task.completed = completed;
+        });
+    }
+}
+
+impl Progress {
+    fn transition_to_canceled(self: &Arc<Self>) {
+        self.transition_state(|state| {
+            if ![State::Paused, State::Running].contains(&state) {
+                return None;
+            }
+
+            // Make sure any outstanding work guards won't count their abandoned work:
+            self.atomic_state
+                .cancellations
+                .fetch_add(1, Ordering::Relaxed);
+
+            Some(State::Canceled)
+        });
+    }
+
+    /// Cancels `self` and its sub-progresses, regardless of their `is_cancelable`.
+    fn force_cancel_subtree(self: &Arc<Self>) {
+        let result = self.try_walk(|progress| {
+            progress.transition_to_canceled();
+            Ok::<(), Infallible>(())
+        });
+
+        result.unwrap_or_else(|never| match never {})
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        let relationships = self.relationships.get_mut();
+
+        if self.state.get_mut().on_root_drop == OnRootDrop::CancelSubtree
+            && relationships.parent.upgrade().is_none()
+        {
+            for child in relationships.children.values() {
+                child.force_cancel_subtree();
+            }
         }
 
-        for child in self.relationships.read().children.values() {
-            child.cancel();
+        // Tear down the subtree iteratively (rather than recursively, via each child's drop),
+        // to support trees of arbitrary depth without overflowing the stack:
+        let mut orphans: Vec<_> = relationships.children.drain().collect();
+
+        while let Some(child) = orphans.pop() {
+            // Children that are still referenced elsewhere get to keep their own children:
+            if Arc::strong_count(&child) == 1 {
+                let mut relationships = child.relationships.write();
+                orphans.extend(relationships.children.drain());
+            }
         }
     }
 }
 
+/// Creates a progress object for the given `task` as the new root of `children`,
+/// emitting relevant events (of the whole combined tree) to `observer`.
+///
+/// Each of `children` (typically the roots of independent trees) gets
+/// [reparented](Progress::reparent) to the new root, with their subtrees
+/// adopting its observer (replacing their own) and sharing its generation counter.
+///
+/// Returned are the combined tree's root progress, as well as a `Reporter`
+/// which is used on the receiving end of the channel for obtaining reports.
+pub fn combine(
+    children: Vec<Arc<Progress>>,
+    task: Task,
+    observer: Arc<dyn Observer>,
+) -> (Arc<Progress>, Weak<impl Reporter + Controller>) {
+    let (root, reporter) = Progress::new(task, observer);
+
+    for child in &children {
+        child.reparent(&root);
+    }
+
+    (root, reporter)
+}
+
 #[doc(hidden)]
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_utils {
@@ -791,6 +2348,16 @@ pub mod test_utils {
         Progress::new(Task::default(), observer)
     }
 
+    #[doc(hidden)]
+    pub fn make_stand_alone_untracked(
+        observer: Option<Arc<dyn Observer>>,
+    ) -> (Arc<Progress>, Weak<impl Reporter>) {
+        let observer = observer.unwrap_or_else(|| Arc::new(NopObserver));
+        crate::ProgressBuilder::new(Task::default())
+            .track_generations(false)
+            .build(observer)
+    }
+
     #[doc(hidden)]
     pub fn make_hierarchy() -> (Arc<Vec<Arc<Progress>>>, Weak<impl Reporter>) {
         let (parent, reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
@@ -813,5 +2380,7 @@ pub mod test_utils {
     }
 }
 
+mod children;
+
 #[cfg(test)]
 mod tests;
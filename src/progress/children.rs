@@ -0,0 +1,60 @@
+//! A progress' insertion-ordered children.
+
+use std::{collections::HashMap, sync::Arc};
+
+use super::{Progress, ProgressId};
+
+/// A progress' children, preserving their order of insertion,
+/// while still providing constant-time lookups by identifier.
+#[derive(Default)]
+pub(super) struct Children {
+    progresses: Vec<Arc<Progress>>,
+    indices: HashMap<ProgressId, usize>,
+}
+
+impl Children {
+    pub(super) fn contains_key(&self, id: &ProgressId) -> bool {
+        self.indices.contains_key(id)
+    }
+
+    pub(super) fn get(&self, id: &ProgressId) -> Option<&Arc<Progress>> {
+        self.indices.get(id).map(|&index| &self.progresses[index])
+    }
+
+    /// Inserts `progress` after all existing children,
+    /// or in place of an existing child with the same `id`.
+    pub(super) fn insert(&mut self, id: ProgressId, progress: Arc<Progress>) {
+        if let Some(&index) = self.indices.get(&id) {
+            self.progresses[index] = progress;
+        } else {
+            self.indices.insert(id, self.progresses.len());
+            self.progresses.push(progress);
+        }
+    }
+
+    /// Removes the child with the given `id`, preserving the order of the remaining children.
+    pub(super) fn remove(&mut self, id: &ProgressId) -> Option<Arc<Progress>> {
+        let index = self.indices.remove(id)?;
+
+        let progress = self.progresses.remove(index);
+
+        for progress in &self.progresses[index..] {
+            *self.indices.get_mut(&progress.id).unwrap() -= 1;
+        }
+
+        Some(progress)
+    }
+
+    pub(super) fn keys(&self) -> impl Iterator<Item = &ProgressId> {
+        self.progresses.iter().map(|progress| &progress.id)
+    }
+
+    pub(super) fn values(&self) -> std::slice::Iter<'_, Arc<Progress>> {
+        self.progresses.iter()
+    }
+
+    pub(super) fn drain(&mut self) -> std::vec::Drain<'_, Arc<Progress>> {
+        self.indices.clear();
+        self.progresses.drain(..)
+    }
+}
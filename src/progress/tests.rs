@@ -1,6 +1,7 @@
 use std::sync::Mutex;
 
 use super::*;
+use crate::{report::Timings, ControlError, MockClock, OnRootDrop, ProgressBuilder};
 
 struct NopObserver;
 
@@ -48,6 +49,16 @@ impl SpyObserver {
             .collect()
     }
 
+    fn attachment_events(&self) -> Vec<AttachmentEvent> {
+        self.events()
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::Attachment(event) => Some(event.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
     fn detachment_events(&self) -> Vec<DetachmentEvent> {
         self.events()
             .into_iter()
@@ -57,6 +68,16 @@ impl SpyObserver {
             })
             .collect()
     }
+
+    fn state_change_events(&self) -> Vec<StateChangeEvent> {
+        self.events()
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::StateChange(event) => Some(event.clone()),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 impl Observer for SpyObserver {
@@ -78,6 +99,23 @@ fn id_monotonically_increments() {
     }
 }
 
+#[test]
+fn new_silent() {
+    let (progress, weak_reporter) = Progress::new_silent(Task::default().total(10));
+
+    progress.set_completed(5);
+    progress.info(|| "test");
+
+    let report = weak_reporter.upgrade().unwrap().report();
+
+    assert_eq!(report.completed, 5);
+    assert_eq!(report.total, 10);
+
+    drop(progress);
+
+    assert!(weak_reporter.upgrade().is_none());
+}
+
 mod no_reference_cycles {
     use super::*;
 
@@ -202,9 +240,399 @@ mod removal {
     }
 }
 
+mod attachment {
+    use super::*;
+
+    #[test]
+    fn reparent() {
+        let (observer, erased_observer) = SpyObserver::new();
+
+        let (root, reporter) = Progress::new(Task::default(), erased_observer);
+        let old_parent = Progress::new_with_parent(Task::default(), &root);
+        let new_parent = Progress::new_with_parent(Task::default(), &root);
+        let child = Progress::new_with_parent(Task::default().total(2), &old_parent);
+        let grandchild = Progress::new_with_parent(Task::default().total(2), &child);
+
+        let events_before = observer.events_len();
+
+        child.reparent(&new_parent);
+
+        assert_eq!(child.parent().unwrap().id(), new_parent.id());
+        assert_eq!(grandchild.parent().unwrap().id(), child.id());
+        assert_eq!(old_parent.children().count(), 0);
+        assert_eq!(new_parent.child(child.id()).unwrap().id(), child.id());
+
+        assert_eq!(
+            observer.events()[events_before..],
+            [
+                Event::Detachment(DetachmentEvent { id: child.id() }),
+                Event::Update(UpdateEvent {
+                    id: old_parent.id()
+                }),
+                Event::Attachment(AttachmentEvent {
+                    id: child.id(),
+                    parent_id: new_parent.id(),
+                }),
+                Event::Update(UpdateEvent {
+                    id: new_parent.id()
+                }),
+            ]
+        );
+
+        let report = reporter.upgrade().unwrap().report();
+
+        assert_eq!(report.total, 4);
+        assert_eq!(report.find(old_parent.id()).unwrap().total, 0);
+        assert_eq!(report.find(new_parent.id()).unwrap().total, 4);
+    }
+
+    #[test]
+    fn reparent_across_trees() {
+        let (old_observer, erased_old_observer) = SpyObserver::new();
+        let (new_observer, erased_new_observer) = SpyObserver::new();
+
+        let (old_parent, _reporter) = Progress::new(Task::default(), erased_old_observer);
+        let (new_parent, _reporter) = Progress::new(Task::default(), erased_new_observer);
+        let child = Progress::new_with_parent(Task::default(), &old_parent);
+
+        child.reparent(&new_parent);
+
+        assert_eq!(
+            old_observer.detachment_events(),
+            vec![DetachmentEvent { id: child.id() }]
+        );
+        assert_eq!(old_observer.attachment_events().len(), 1);
+
+        assert_eq!(new_observer.detachment_events(), vec![]);
+        assert_eq!(
+            new_observer.attachment_events(),
+            vec![AttachmentEvent {
+                id: child.id(),
+                parent_id: new_parent.id(),
+            }]
+        );
+    }
+
+    #[test]
+    fn reparent_adopts_observer() {
+        let (parent_observer, erased_parent_observer) = SpyObserver::new();
+
+        let (parent, _reporter) = Progress::new(Task::default(), erased_parent_observer);
+        let (child, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let grandchild = Progress::new_with_parent(Task::default(), &child);
+
+        child.reparent(&parent);
+
+        let update_events_before = parent_observer.update_events().len();
+
+        grandchild.update(|_| {});
+
+        assert_eq!(
+            parent_observer.update_events().len(),
+            update_events_before + 1
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "cannot reparent a progress under itself or one of its sub-progresses"
+    )]
+    fn reparent_under_descendant_panics() {
+        let (parent, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let child = Progress::new_with_parent(Task::default(), &parent);
+        let grandchild = Progress::new_with_parent(Task::default(), &child);
+
+        parent.reparent(&grandchild);
+    }
+
+    #[test]
+    fn with_child() {
+        let (spy, observer) = SpyObserver::new();
+
+        let (parent, _reporter) = Progress::new(Task::default(), observer);
+
+        let child_id = parent.with_child(Task::default().total(2), |child| {
+            assert_eq!(parent.child(child.id()).unwrap().id(), child.id());
+            assert_eq!(spy.detachment_events(), vec![]);
+
+            child.set_completed(1);
+
+            child.id()
+        });
+
+        assert!(parent.child(child_id).is_none());
+        assert_eq!(
+            spy.detachment_events(),
+            vec![DetachmentEvent { id: child_id }]
+        );
+    }
+
+    #[test]
+    fn detach_all_children() {
+        let (observer, erased_observer) = SpyObserver::new();
+
+        let (parent, _reporter) = Progress::new(Task::default(), erased_observer);
+        let children: Vec<_> = (0..3)
+            .map(|_| Progress::new_with_parent(Task::default(), &parent))
+            .collect();
+
+        let (child_observer, erased_child_observer) = SpyObserver::new();
+
+        let update_events_before = observer.update_events().len();
+
+        parent.detach_all_children(|_| Arc::clone(&erased_child_observer));
+
+        assert_eq!(observer.detachment_events().len(), 3);
+        assert_eq!(observer.update_events().len(), update_events_before + 1);
+        assert_eq!(parent.children().count(), 0);
+
+        for child in &children {
+            assert!(child.parent().is_none());
+
+            child.update(|_| {});
+        }
+
+        assert_eq!(child_observer.update_events().len(), 3);
+    }
+
+    #[test]
+    fn attach_child_sets_parent() {
+        let (parent, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let (child, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        let _observer = parent.attach_child(&child);
+
+        assert_eq!(child.parent().unwrap().id(), parent.id());
+    }
+
+    #[test]
+    fn attach_child_emits_attachment_event() {
+        let (observer, erased_observer) = SpyObserver::new();
+
+        let (parent, _reporter) = Progress::new(Task::default(), erased_observer);
+        let (child, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        let _observer = parent.attach_child(&child);
+
+        assert_eq!(
+            observer.attachment_events(),
+            vec![AttachmentEvent {
+                id: child.id(),
+                parent_id: parent.id(),
+            }]
+        );
+    }
+
+    #[test]
+    fn attach_already_attached_child() {
+        let (old_observer, erased_old_observer) = SpyObserver::new();
+        let (new_observer, erased_new_observer) = SpyObserver::new();
+
+        let (old_parent, old_reporter) = Progress::new(Task::default(), erased_old_observer);
+        let (new_parent, new_reporter) = Progress::new(Task::default(), erased_new_observer);
+        let child = Progress::new_with_parent(Task::default().total(2), &old_parent);
+        let grandchild = Progress::new_with_parent(Task::default().total(2), &child);
+
+        let _observer = new_parent.attach_child(&child);
+
+        assert_eq!(child.parent().unwrap().id(), new_parent.id());
+        assert!(old_parent.child(child.id()).is_none());
+        assert_eq!(
+            old_observer.detachment_events(),
+            vec![DetachmentEvent { id: child.id() }]
+        );
+
+        // The child only gets accounted for by its new parent:
+        assert_eq!(old_reporter.upgrade().unwrap().report().total, 0);
+        assert_eq!(new_reporter.upgrade().unwrap().report().total, 4);
+
+        // The child's whole sub-tree adopts the new parent's observer:
+        let old_events_before = old_observer.events_len();
+        let new_update_events_before = new_observer.update_events().len();
+
+        grandchild.update(|_| {});
+
+        assert_eq!(old_observer.events_len(), old_events_before);
+        assert_eq!(
+            new_observer.update_events().len(),
+            new_update_events_before + 1
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "not a child")]
+    fn detach_formerly_attached_child_panics() {
+        let (old_parent, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let (new_parent, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let child = Progress::new_with_parent(Task::default(), &old_parent);
+
+        let _observer = new_parent.attach_child(&child);
+
+        old_parent.detach_child(&child, Arc::new(NopObserver));
+    }
+
+    #[test]
+    fn hierarchy_emits_attachment_events() {
+        let (observer, erased_observer) = SpyObserver::new();
+
+        let (parent, _reporter) = Progress::new(Task::default(), erased_observer);
+
+        for _ in 1..=10 {
+            let child = Progress::new_with_parent(Task::default(), &parent);
+
+            for _ in 1..=10 {
+                Progress::new_with_parent(Task::default(), &child);
+            }
+        }
+
+        let attachment_events = observer.attachment_events();
+
+        assert_eq!(attachment_events.len(), 110);
+        assert_eq!(
+            attachment_events
+                .iter()
+                .filter(|event| event.parent_id == parent.id())
+                .count(),
+            10
+        );
+    }
+
+    #[test]
+    fn attach_ancestor_panics() {
+        let (grandparent, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let parent = Progress::new_with_parent(Task::default(), &grandparent);
+        let child = Progress::new_with_parent(Task::default(), &parent);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            child.attach_child(&grandparent);
+        }));
+
+        let message = *result.unwrap_err().downcast::<&str>().unwrap();
+        assert_eq!(
+            message,
+            "cannot attach a progress to itself or one of its sub-progresses"
+        );
+
+        // The tree remains unchanged:
+        assert!(grandparent.parent().is_none());
+        assert_eq!(child.children().count(), 0);
+        assert_eq!(parent.parent().unwrap().id(), grandparent.id());
+        assert_eq!(child.parent().unwrap().id(), parent.id());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot attach a progress to itself or one of its sub-progresses")]
+    fn attach_self_panics() {
+        let (progress, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        progress.attach_child(&progress);
+    }
+
+    #[test]
+    fn scoped_detach_restores_observer() {
+        let (parent_observer, erased_parent_observer) = SpyObserver::new();
+        let (child_observer, erased_child_observer) = SpyObserver::new();
+
+        let (parent, _reporter) = Progress::new(Task::default(), erased_parent_observer);
+        let (child, _reporter) = Progress::new(Task::default(), erased_child_observer);
+
+        let attachment = parent.attach_child_scoped(&child);
+
+        child.update(|_| {});
+
+        assert_eq!(child_observer.events_len(), 0);
+
+        attachment.detach();
+
+        assert!(child.parent().is_none());
+        assert!(parent.child(child.id()).is_none());
+        assert_eq!(parent_observer.detachment_events().len(), 1);
+
+        child.update(|_| {});
+
+        assert_eq!(child_observer.update_events().len(), 1);
+    }
+
+    #[test]
+    fn scoped_detach_on_drop() {
+        let (parent, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let (child, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        let attachment = parent.attach_child_scoped(&child);
+        drop(attachment);
+
+        assert_eq!(child.parent().unwrap().id(), parent.id());
+
+        let attachment = parent.attach_child_scoped(&child).detach_on_drop(true);
+        drop(attachment);
+
+        assert!(child.parent().is_none());
+    }
+}
+
 mod message {
     use super::*;
 
+    #[test]
+    fn max_priority() {
+        let (root, reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let reporter = reporter.upgrade().unwrap();
+
+        let child = Progress::new_with_parent(Task::default(), &root);
+        let grandchild = Progress::new_with_parent(Task::default(), &child);
+
+        assert_eq!(reporter.report().max_priority(), None);
+
+        let baseline = reporter.report().last_change();
+
+        grandchild.warn(|| "warning");
+        child.info(|| "info");
+
+        let report = reporter.report();
+
+        assert_eq!(report.max_priority(), Some(PriorityLevel::Warn));
+        assert_eq!(report.highest_priority_seen, None);
+        assert_eq!(
+            report.find(grandchild.id()).unwrap().highest_priority_seen,
+            Some(PriorityLevel::Warn)
+        );
+
+        // Raising the highest priority level counts as a change:
+        let partial_report = reporter.partial_report(baseline).unwrap();
+
+        assert_eq!(partial_report.max_priority(), Some(PriorityLevel::Warn));
+
+        grandchild.clear_messages_seen();
+
+        assert_eq!(reporter.report().max_priority(), Some(PriorityLevel::Info));
+    }
+
+    #[test]
+    fn with_fields() {
+        let (observer, erased_observer) = SpyObserver::new();
+
+        let (progress, _reporter) = Progress::new(Task::default(), erased_observer);
+
+        progress.message_with(
+            || "test",
+            PriorityLevel::Info,
+            [("file", "x.rs".to_owned()), ("bytes", 1024.to_string())],
+        );
+        progress.message(|| "test", PriorityLevel::Info);
+
+        let message_events = observer.message_events();
+
+        assert_eq!(message_events.len(), 2);
+        assert_eq!(
+            message_events[0].fields,
+            vec![
+                ("file".into(), "x.rs".into()),
+                ("bytes".into(), "1024".into())
+            ]
+        );
+        assert_eq!(message_events[1].fields, vec![]);
+    }
+
     #[test]
     fn gets_filtered_by_min_priority_level() {
         let (observer, erased_observer) = SpyObserver::new();
@@ -226,6 +654,44 @@ mod message {
         }
     }
 
+    #[test]
+    fn scoped_min_priority_level() {
+        let (progress, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        let local_level = || {
+            progress
+                .atomic_state
+                .min_priority_level
+                .load(Ordering::Relaxed)
+        };
+
+        {
+            let _guard = progress.with_min_priority_level(PriorityLevel::Warn);
+
+            assert_eq!(progress.min_priority_level(), PriorityLevel::Warn);
+
+            {
+                let _guard = progress.with_min_priority_level(PriorityLevel::Error);
+
+                assert_eq!(progress.min_priority_level(), PriorityLevel::Error);
+            }
+
+            assert_eq!(progress.min_priority_level(), PriorityLevel::Warn);
+        }
+
+        assert_eq!(local_level(), None);
+
+        progress.set_min_priority_level(Some(PriorityLevel::Debug));
+
+        {
+            let _guard = progress.with_min_priority_level(PriorityLevel::Info);
+
+            assert_eq!(progress.min_priority_level(), PriorityLevel::Info);
+        }
+
+        assert_eq!(local_level(), Some(PriorityLevel::Debug));
+    }
+
     #[test]
     fn gets_delivered() {
         let (observer, erased_observer) = SpyObserver::new();
@@ -247,6 +713,7 @@ mod message {
                 id: progress.id(),
                 message: message.into(),
                 priority,
+                fields: vec![],
             })
             .collect();
 
@@ -280,6 +747,126 @@ mod message {
     }
 }
 
+mod ancestry {
+    use super::*;
+
+    #[test]
+    fn grandchild_of_root() {
+        let (root, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let child = Progress::new_with_parent(Task::default(), &root);
+        let grandchild = Progress::new_with_parent(Task::default(), &child);
+
+        assert!(grandchild.is_descendant_of(&root));
+        assert!(root.is_ancestor_of(&grandchild));
+
+        assert!(!root.is_descendant_of(&grandchild));
+        assert!(!grandchild.is_ancestor_of(&root));
+    }
+
+    #[test]
+    fn siblings() {
+        let (root, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let child = Progress::new_with_parent(Task::default(), &root);
+        let sibling = Progress::new_with_parent(Task::default(), &root);
+
+        assert!(!child.is_descendant_of(&sibling));
+        assert!(!child.is_ancestor_of(&sibling));
+    }
+
+    #[test]
+    fn self_is_neither() {
+        let (root, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        assert!(!root.is_descendant_of(&root));
+        assert!(!root.is_ancestor_of(&root));
+    }
+}
+
+mod message_buffer {
+    use super::*;
+
+    #[test]
+    fn disabled_by_default() {
+        let (progress, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        progress.info(|| "test");
+
+        assert_eq!(progress.message_buffer_capacity(), 0);
+        assert_eq!(progress.recent_messages(), vec![]);
+    }
+
+    #[test]
+    fn retains_most_recent() {
+        let (progress, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        progress.set_message_history_capacity(3);
+
+        for message in ["first", "second", "third", "fourth", "fifth"] {
+            progress.info(|| message);
+        }
+
+        let messages: Vec<_> = progress
+            .recent_messages()
+            .into_iter()
+            .map(|event| (event.message, event.priority))
+            .collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                ("third".into(), PriorityLevel::Info),
+                ("fourth".into(), PriorityLevel::Info),
+                ("fifth".into(), PriorityLevel::Info),
+            ]
+        );
+
+        progress.set_message_history_capacity(1);
+
+        assert_eq!(progress.message_buffer_capacity(), 1);
+        assert_eq!(progress.recent_messages().len(), 1);
+        assert_eq!(progress.recent_messages()[0].message, "fifth");
+
+        progress.set_message_history_capacity(0);
+        progress.info(|| "sixth");
+
+        assert_eq!(progress.recent_messages(), vec![]);
+    }
+
+    #[test]
+    fn inherited_by_children() {
+        let (parent, _reporter) = ProgressBuilder::new(Task::default())
+            .message_buffer(2)
+            .build(Arc::new(NopObserver));
+        let child = Progress::new_with_parent(Task::default(), &parent);
+        let overriding_child = ProgressBuilder::new(Task::default())
+            .message_buffer(0)
+            .build_with_parent(&parent);
+
+        assert_eq!(child.message_buffer_capacity(), 2);
+        assert_eq!(overriding_child.message_buffer_capacity(), 0);
+
+        for message in ["first", "second", "third"] {
+            child.info(|| message);
+            overriding_child.info(|| message);
+        }
+
+        let (observer, erased_observer) = SpyObserver::new();
+
+        child.replay_messages(&*erased_observer);
+        overriding_child.replay_messages(&*erased_observer);
+
+        let messages: Vec<_> = observer
+            .message_events()
+            .into_iter()
+            .map(|event| (event.id, event.message))
+            .collect();
+
+        assert_eq!(
+            messages,
+            vec![(child.id(), "second".into()), (child.id(), "third".into())]
+        );
+    }
+}
+
 mod update {
     use super::*;
 
@@ -313,61 +900,1606 @@ mod update {
     }
 }
 
-mod debug {
-    use super::*;
+#[test]
+fn is_indeterminate() {
+    let (parent, reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+    let child = Progress::new_with_parent(Task::default().total(2), &parent);
 
-    #[test]
-    fn fmt() {
-        let observer = Arc::new(NopObserver);
+    assert!(parent.is_indeterminate());
+    assert!(!child.is_indeterminate());
 
-        let (progress, _) = Progress::new(Task::default(), observer);
+    // Only the progress' own task is taken into account:
+    assert!(!reporter.upgrade().unwrap().report().is_indeterminate);
 
-        let id = progress.id();
-        let report = Report {
-            progress_id: id,
-            label: None,
-            completed: 0,
-            total: 0,
-            fraction: 0.0,
-            is_indeterminate: true,
-            state: State::Running,
-            subreports: vec![],
-            last_change: Generation(0),
-        };
+    parent.set_total(3);
 
-        let actual = format!("{progress:?}");
-        let expected =
+    assert!(!parent.is_indeterminate());
+
+    parent.set_total(0);
+    parent.set_weight_total(1.0);
+
+    assert!(!parent.is_indeterminate());
+}
+
+#[test]
+fn remaining() {
+    assert_eq!(Task::default().remaining(), 0);
+    assert_eq!(Task::default().total(3).completed(1).remaining(), 2);
+    assert_eq!(Task::default().total(1).completed(3).remaining(), 0);
+
+    let (parent, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+    assert!(parent.report().is_indeterminate);
+    assert_eq!(parent.report().remaining(), 0);
+
+    let child = Progress::new_with_parent(Task::default().total(3), &parent);
+    parent.set_total(2);
+    child.set_completed(1);
+
+    assert_eq!(parent.report().remaining(), 4);
+}
+
+#[test]
+fn aggregate_discrete() {
+    let (progresses, _weak_reporter) = test_utils::make_hierarchy();
+
+    for (index, progress) in progresses.iter().enumerate() {
+        progress.update(|task| {
+            task.completed = index % 3;
+            task.total = index % 5;
+        });
+    }
+
+    for progress in progresses.iter() {
+        assert_eq!(progress.aggregate_discrete(), progress.report().discrete());
+    }
+}
+
+mod fraction_strict {
+    use super::*;
+
+    fn report(task: Task) -> Report {
+        let (_progress, reporter) = Progress::new(task, Arc::new(NopObserver));
+
+        reporter.upgrade().unwrap().report()
+    }
+
+    #[test]
+    fn completed_without_total() {
+        let report = report(Task::default().completed(5));
+
+        assert_eq!(report.discrete(), (5, 0));
+        assert_eq!(report.fraction, 1.0);
+        assert!(!report.is_indeterminate);
+        assert_eq!(report.fraction_strict(), None);
+    }
+
+    #[test]
+    fn weighted_completed_without_total() {
+        let report = report(Task::default().weight_completed(2.5));
+
+        assert_eq!(report.weight_completed, Some(2.5));
+        assert_eq!(report.weight_total, Some(0.0));
+        assert_eq!(report.fraction, 1.0);
+        assert_eq!(report.fraction_strict(), None);
+    }
+
+    #[test]
+    fn empty() {
+        let report = report(Task::default());
+
+        assert_eq!(report.fraction, 0.0);
+        assert_eq!(report.fraction_strict(), None);
+    }
+
+    #[test]
+    fn partial() {
+        let report = report(Task::default().completed(5).total(10));
+
+        assert_eq!(report.fraction_strict(), Some(0.5));
+    }
+
+    #[test]
+    fn overshooting() {
+        let report = report(Task::default().completed(15).total(10));
+
+        assert_eq!(report.discrete(), (10, 15));
+        assert_eq!(report.fraction_strict(), Some(10.0 / 15.0));
+    }
+}
+
+mod update_batch {
+    use super::*;
+
+    #[derive(Default)]
+    struct BatchSpyObserver {
+        batches: Mutex<Vec<Vec<Event>>>,
+    }
+
+    impl Observer for BatchSpyObserver {
+        fn observe(&self, event: Event) {
+            self.batches.lock().unwrap().push(vec![event]);
+        }
+
+        fn observe_batch(&self, events: Vec<Event>) {
+            self.batches.lock().unwrap().push(events);
+        }
+    }
+
+    #[test]
+    fn preserves_order() {
+        let observer = Arc::new(BatchSpyObserver::default());
+
+        let (progress, _reporter) = Progress::new(
+            Task::default().total(3),
+            Arc::<BatchSpyObserver>::clone(&observer),
+        );
+        progress.set_auto_complete(true);
+
+        let id = progress.id();
+
+        progress.update_batch([
+            Box::new(|task: &mut Task| task.completed = 1) as Box<dyn FnOnce(&mut Task)>,
+            Box::new(|task: &mut Task| task.label = Some("label".into())),
+            Box::new(|task: &mut Task| task.completed = 3),
+        ]);
+
+        let batches = observer.batches.lock().unwrap().clone();
+
+        assert_eq!(
+            batches,
+            vec![vec![
+                Event::Update(UpdateEvent { id }),
+                Event::Update(UpdateEvent { id }),
+                Event::Update(UpdateEvent { id }),
+                Event::StateChange(StateChangeEvent {
+                    id,
+                    old: State::Running,
+                    new: State::Finished,
+                }),
+            ]]
+        );
+        assert_eq!(progress.completed(), 3);
+        assert_eq!(progress.label().as_deref(), Some("label"));
+    }
+
+    #[test]
+    fn empty() {
+        let observer = Arc::new(BatchSpyObserver::default());
+
+        let (progress, _reporter) =
+            Progress::new(Task::default(), Arc::<BatchSpyObserver>::clone(&observer));
+
+        progress.update_batch(std::iter::empty::<fn(&mut Task)>());
+
+        assert!(observer.batches.lock().unwrap().is_empty());
+    }
+}
+
+mod auto_complete {
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let (observer, erased_observer) = SpyObserver::new();
+
+        let (progress, _weak_reporter) = Progress::new(Task::default().total(2), erased_observer);
+
+        progress.set_completed(2);
+
+        assert_eq!(progress.state(), State::Running);
+        assert!(observer.state_change_events().is_empty());
+    }
+
+    #[test]
+    fn enabled() {
+        let (observer, erased_observer) = SpyObserver::new();
+
+        let (progress, _weak_reporter) = Progress::new(Task::default().total(2), erased_observer);
+        progress.set_auto_complete(true);
+
+        progress.increment_completed();
+
+        assert_eq!(progress.state(), State::Running);
+
+        progress.increment_completed();
+
+        assert_eq!(progress.state(), State::Finished);
+        assert_eq!(
+            observer.state_change_events(),
+            vec![StateChangeEvent {
+                id: progress.id(),
+                old: State::Running,
+                new: State::Finished,
+            }]
+        );
+    }
+
+    #[test]
+    fn indeterminate() {
+        let (progress, _weak_reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        progress.set_auto_complete(true);
+
+        progress.increment_completed();
+
+        assert_eq!(progress.state(), State::Running);
+    }
+
+    #[test]
+    fn paused() {
+        let (progress, _weak_reporter) =
+            Progress::new(Task::default().total(1), Arc::new(NopObserver));
+        progress.set_auto_complete(true);
+        progress.set_state(State::Paused);
+
+        progress.increment_completed();
+
+        assert_eq!(progress.state(), State::Paused);
+    }
+}
+
+mod increment_completed {
+    use super::*;
+
+    #[test]
+    fn saturates() {
+        let (progress, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        progress.set_completed(usize::MAX - 1);
+
+        progress.increment_completed();
+        assert_eq!(progress.completed(), usize::MAX);
+
+        progress.increment_completed();
+        assert_eq!(progress.completed(), usize::MAX);
+
+        progress.set_completed(usize::MAX - 10);
+
+        progress.increment_completed_by(100);
+        assert_eq!(progress.completed(), usize::MAX);
+    }
+}
+
+mod set_completed_checked {
+    use super::*;
+
+    #[test]
+    fn skips_unchanged() {
+        let (observer, erased_observer) = SpyObserver::new();
+
+        let (progress, _reporter) = Progress::new(Task::default(), erased_observer);
+
+        assert!(progress.set_completed_checked(5));
+        assert!(!progress.set_completed_checked(5));
+
+        assert_eq!(progress.completed(), 5);
+        assert_eq!(observer.update_events().len(), 1);
+    }
+}
+
+mod unit_guard {
+    use super::*;
+
+    #[test]
+    fn increments_on_drop() {
+        let (progress, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        let guards: Vec<_> = (0..3).map(|_| progress.unit_guard()).collect();
+
+        assert_eq!(progress.completed(), 0);
+
+        drop(guards);
+
+        assert_eq!(progress.completed(), 3);
+    }
+
+    #[test]
+    fn abandoned_on_cancel() {
+        let (progress, _reporter) =
+            Progress::new(Task::default().cancelable(true), Arc::new(NopObserver));
+
+        let guard = progress.unit_guard();
+
+        progress.cancel().unwrap();
+
+        drop(guard);
+
+        assert_eq!(progress.completed(), 0);
+    }
+
+    #[test]
+    fn forget() {
+        let (progress, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        for index in 0..4 {
+            let guard = progress.unit_guard();
+
+            if index % 2 == 0 {
+                guard.forget();
+            }
+        }
+
+        assert_eq!(progress.completed(), 2);
+    }
+
+    #[test]
+    fn early_return() {
+        fn work(progress: &Arc<Progress>, fail: bool) -> Result<(), ()> {
+            let _guard = progress.unit_guard();
+
+            if fail {
+                return Err(());
+            }
+
+            Ok(())
+        }
+
+        let (progress, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        assert!(work(&progress, false).is_ok());
+        assert!(work(&progress, true).is_err());
+
+        assert_eq!(progress.completed(), 2);
+    }
+
+    #[test]
+    fn does_not_keep_alive() {
+        let (progress, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        let guard = progress.unit_guard();
+
+        drop(progress);
+        drop(guard);
+    }
+}
+
+mod children {
+    use super::*;
+
+    fn child_ids(progress: &Arc<Progress>) -> Vec<ProgressId> {
+        progress.children().map(|child| child.id()).collect()
+    }
+
+    #[test]
+    fn insertion_order() {
+        let (parent, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let (orphan, _orphan_reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        let children: Vec<_> = (0..10)
+            .map(|_| Progress::new_with_parent(Task::default(), &parent))
+            .collect();
+        let mut expected: Vec<_> = children.iter().map(|child| child.id()).collect();
+
+        assert_eq!(child_ids(&parent), expected);
+
+        // Attached progresses get appended, regardless of their creation:
+        parent.attach_child(&orphan);
+        expected.push(orphan.id());
+
+        assert_eq!(child_ids(&parent), expected);
+
+        // Detaching preserves the order of the remaining children:
+        parent.detach_child(&children[3], Arc::new(NopObserver));
+        expected.remove(3);
+
+        assert_eq!(child_ids(&parent), expected);
+
+        for id in &expected {
+            assert_eq!(parent.child(*id).unwrap().id(), *id);
+        }
+
+        assert!(parent.child(children[3].id()).is_none());
+    }
+}
+
+mod subtree_report {
+    use super::*;
+
+    #[test]
+    fn descendant() {
+        let (root, reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let reporter = reporter.upgrade().unwrap();
+
+        let child = Progress::new_with_parent(Task::default(), &root);
+        let grandchild = Progress::new_with_parent(Task::default().total(7), &child);
+        let _great_grandchild = Progress::new_with_parent(Task::default(), &grandchild);
+
+        let report = reporter.subtree_report(grandchild.id()).unwrap();
+
+        assert_eq!(report.progress_id, grandchild.id());
+        assert_eq!(report.total, 7);
+        assert_eq!(report.subreports.len(), 1);
+
+        assert_eq!(
+            reporter.subtree_report(root.id()).unwrap().progress_id,
+            root.id()
+        );
+    }
+
+    #[test]
+    fn missing() {
+        let (root, reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let reporter = reporter.upgrade().unwrap();
+
+        let leaf = Progress::new_with_parent(Task::default(), &root);
+
+        assert_eq!(reporter.subtree_report(ProgressId::new_unique()), None);
+        assert_eq!(leaf.subtree_report(root.id()), None);
+        assert_eq!(
+            leaf.subtree_report(leaf.id()).unwrap().progress_id,
+            leaf.id()
+        );
+    }
+}
+
+mod path {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let (root, reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let reporter = reporter.upgrade().unwrap();
+
+        let children: Vec<_> = (0..3)
+            .map(|_| Progress::new_with_parent(Task::default(), &root))
+            .collect();
+        let grandchild = Progress::new_with_parent(Task::default().total(7), &children[1]);
+
+        assert_eq!(root.path(), Some(NodePath::root()));
+        assert_eq!(grandchild.path(), Some(NodePath::from(vec![1, 0])));
+
+        let report = reporter
+            .report_at_path(&grandchild.path().unwrap())
+            .unwrap();
+
+        assert_eq!(report.progress_id, grandchild.id());
+        assert_eq!(report.total, 7);
+
+        assert_eq!(
+            reporter
+                .report_at_path(&NodePath::root())
+                .unwrap()
+                .progress_id,
+            root.id()
+        );
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let (root, reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let reporter = reporter.upgrade().unwrap();
+
+        let child = Progress::new_with_parent(Task::default(), &root);
+
+        assert_eq!(reporter.report_at_path(&NodePath::from(vec![1])), None);
+        assert_eq!(reporter.report_at_path(&NodePath::from(vec![0, 0])), None);
+
+        root.detach_child(&child, Arc::new(NopObserver));
+
+        assert_eq!(reporter.report_at_path(&NodePath::from(vec![0])), None);
+    }
+
+    #[test]
+    fn concurrent_detachment() {
+        let (root, _) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..1000 {
+                        let child = Progress::new_with_parent(Task::default(), &root);
+                        let grandchild = Progress::new_with_parent(Task::default(), &child);
+
+                        scope.spawn(move || child.detach_from_parent(Arc::new(NopObserver)));
+
+                        // Depending on timing the path is either relative to the former
+                        // or to the new root, or unavailable, but determining it never panics:
+                        if let Some(path) = grandchild.path() {
+                            assert!(path.depth() <= 2);
+                        }
+                    }
+                });
+            }
+        });
+    }
+}
+
+mod track_generations {
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let (spy, observer) = SpyObserver::new();
+
+        let (parent, reporter) = ProgressBuilder::new(Task::default())
+            .track_generations(false)
+            .build(observer);
+        let child = Progress::new_with_parent(Task::default().total(2), &parent);
+
+        let update_events_before = spy.update_events().len();
+
+        child.set_completed(1);
+
+        let report = reporter.upgrade().unwrap().report();
+
+        assert_eq!(report.completed, 1);
+        assert_eq!(report.last_change(), Generation::MIN);
+        assert_eq!(spy.update_events().len(), update_events_before + 1);
+    }
+
+    #[test]
+    fn partial_report_is_full_report() {
+        let (parent, reporter) = ProgressBuilder::new(Task::default())
+            .track_generations(false)
+            .build(Arc::new(NopObserver));
+        let child = Progress::new_with_parent(Task::default().total(2), &parent);
+
+        let reporter = reporter.upgrade().unwrap();
+
+        let partial_report = reporter.partial_report(Generation::MIN).unwrap();
+        assert_eq!(partial_report.discrete(), (0, 2));
+        assert_eq!(partial_report.subreports.len(), 1);
+
+        child.set_completed(1);
+
+        let partial_report = reporter.partial_report(Generation::MAX).unwrap();
+        assert_eq!(partial_report.completed, 1);
+        assert_eq!(partial_report.subreports.len(), 1);
+    }
+
+    #[test]
+    fn overflow() {
+        let (spy, observer) = SpyObserver::new();
+
+        let (parent, _reporter) = Progress::new(Task::default(), observer);
+        let child = Progress::new_with_parent(Task::default(), &parent);
+
+        parent
+            .atomic_state
+            .last_change
+            .swap(Generation(usize::MAX - 1), Ordering::Relaxed);
+
+        let overflow_events = || {
+            spy.events()
+                .into_iter()
+                .filter(|event| matches!(event, Event::GenerationOverflow))
+                .count()
+        };
+
+        child.set_completed(1);
+
+        assert_eq!(overflow_events(), 0);
+        assert_eq!(
+            child.atomic_state.last_change.load(Ordering::Relaxed),
+            Generation::MAX
+        );
+
+        child.set_completed(2);
+
+        assert_eq!(overflow_events(), 1);
+        assert_eq!(
+            child.atomic_state.last_change.load(Ordering::Relaxed),
+            Generation::MIN
+        );
+
+        child.set_completed(3);
+
+        assert_eq!(overflow_events(), 1);
+    }
+
+    #[test]
+    fn single_generation_type() {
+        let (progress, reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        progress.set_completed(1);
+
+        let reporter = reporter.upgrade().unwrap();
+
+        // The generation of reports is the same type that's exported by the crate
+        // and consumed by partial reports (i.e. a single type is used end to end):
+        let last_change: crate::generation::Generation = reporter.report().last_change();
+        let baseline: crate::Generation = last_change;
+
+        assert!(last_change > Generation::MIN);
+        assert!(reporter.partial_report(baseline).is_none());
+        assert!(reporter.partial_report(Generation::MIN).is_some());
+    }
+}
+
+mod elapsed {
+    use super::*;
+
+    #[test]
+    fn hierarchical() {
+        let clock = Arc::new(MockClock::new());
+
+        let (parent, reporter) = ProgressBuilder::new(Task::default())
+            .clock(Arc::<MockClock>::clone(&clock))
+            .build(Arc::new(NopObserver));
+        let reporter = reporter.upgrade().unwrap();
+
+        assert_eq!(reporter.report().elapsed(), Duration::ZERO);
+
+        clock.advance(Duration::from_secs(5));
+
+        let child = Progress::new_with_parent(Task::default(), &parent);
+
+        clock.advance(Duration::from_secs(3));
+
+        let report = reporter.report();
+
+        assert_eq!(report.elapsed(), Duration::from_secs(8));
+        assert_eq!(
+            report.find(child.id()).unwrap().elapsed(),
+            Duration::from_secs(3)
+        );
+    }
+
+    #[test]
+    fn excludes_pauses() {
+        let clock = Arc::new(MockClock::new());
+
+        let (progress, reporter) = ProgressBuilder::new(Task::default().total(4).pausable(true))
+            .clock(Arc::<MockClock>::clone(&clock))
+            .build(Arc::new(NopObserver));
+        let reporter = reporter.upgrade().unwrap();
+
+        clock.advance(Duration::from_secs(10));
+        progress.set_completed(1);
+
+        progress.pause().unwrap();
+        clock.advance(Duration::from_secs(100));
+
+        // Ongoing pauses are excluded, too:
+        let report = reporter.report();
+
+        assert_eq!(report.elapsed(), Duration::from_secs(110));
+        assert_eq!(report.active_elapsed(), Duration::from_secs(10));
+
+        progress.resume().unwrap();
+        clock.advance(Duration::from_secs(10));
+        progress.set_completed(2);
+
+        let report = reporter.report();
+
+        assert_eq!(report.elapsed(), Duration::from_secs(120));
+        assert_eq!(report.active_elapsed(), Duration::from_secs(20));
+
+        // Half of the work took 20s (of activity), so the remainder should take 20s:
+        assert_eq!(report.eta(), Some(Duration::from_secs(20)));
+    }
+
+    #[test]
+    fn starts_paused() {
+        let clock = Arc::new(MockClock::new());
+
+        let (progress, reporter) = ProgressBuilder::new(Task::default().state(State::Paused))
+            .clock(Arc::<MockClock>::clone(&clock))
+            .build(Arc::new(NopObserver));
+        let reporter = reporter.upgrade().unwrap();
+
+        clock.advance(Duration::from_secs(5));
+        progress.set_state(State::Running);
+        clock.advance(Duration::from_secs(2));
+
+        assert_eq!(reporter.report().active_elapsed(), Duration::from_secs(2));
+    }
+}
+
+mod eta {
+    use super::*;
+
+    #[test]
+    fn hierarchical() {
+        let clock = Arc::new(MockClock::new());
+
+        let (parent, reporter) = ProgressBuilder::new(Task::default())
+            .clock(Arc::<MockClock>::clone(&clock))
+            .build(Arc::new(NopObserver));
+        let reporter = reporter.upgrade().unwrap();
+
+        let first = Progress::new_with_parent(Task::default().total(10), &parent);
+        let second = Progress::new_with_parent(Task::default().total(30), &parent);
+
+        assert_eq!(reporter.report().eta(), None);
+
+        clock.advance(Duration::from_secs(10));
+
+        first.set_completed(10);
+
+        let report = reporter.report();
+
+        // A quarter of the tree's work took 10s, so the remainder should take 30s:
+        assert_eq!(report.eta(), Some(Duration::from_secs(30)));
+        assert_eq!(report.subreports.len(), 2);
+
+        second.set_completed(30);
+
+        assert_eq!(reporter.report().eta(), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn indeterminate() {
+        let clock = Arc::new(MockClock::new());
+
+        let (_progress, reporter) = ProgressBuilder::new(Task::default())
+            .clock(Arc::<MockClock>::clone(&clock))
+            .build(Arc::new(NopObserver));
+
+        clock.advance(Duration::from_secs(10));
+
+        assert_eq!(reporter.upgrade().unwrap().report().eta(), None);
+    }
+
+    #[test]
+    fn counts_down_between_updates() {
+        let clock = Arc::new(MockClock::new());
+
+        let (progress, reporter) = ProgressBuilder::new(Task::default().total(4))
+            .clock(Arc::<MockClock>::clone(&clock))
+            .build(Arc::new(NopObserver));
+        let reporter = reporter.upgrade().unwrap();
+
+        clock.advance(Duration::from_secs(10));
+
+        progress.set_completed(1);
+
+        assert_eq!(reporter.report().eta(), Some(Duration::from_secs(30)));
+
+        clock.advance(Duration::from_secs(5));
+
+        // No progress has been reported since, so only the time passed gets deducted:
+        assert_eq!(reporter.report().eta(), Some(Duration::from_secs(25)));
+
+        progress.set_completed(2);
+
+        // Half of the work took 15s:
+        assert_eq!(reporter.report().eta(), Some(Duration::from_secs(15)));
+    }
+
+    #[test]
+    fn pauses_since_last_update() {
+        let clock = Arc::new(MockClock::new());
+
+        let (progress, reporter) = ProgressBuilder::new(Task::default().total(4).pausable(true))
+            .clock(Arc::<MockClock>::clone(&clock))
+            .build(Arc::new(NopObserver));
+        let reporter = reporter.upgrade().unwrap();
+
+        clock.advance(Duration::from_secs(10));
+
+        progress.set_completed(1);
+
+        clock.advance(Duration::from_secs(5));
+
+        progress.pause().unwrap();
+
+        clock.advance(Duration::from_secs(60));
+
+        // Neither pausing, nor the time spent paused affect the estimate:
+        assert_eq!(reporter.report().eta(), Some(Duration::from_secs(25)));
+
+        progress.resume().unwrap();
+
+        clock.advance(Duration::from_secs(5));
+
+        assert_eq!(reporter.report().eta(), Some(Duration::from_secs(20)));
+    }
+}
+
+mod units_per_second {
+    use super::*;
+
+    #[test]
+    fn smoothed() {
+        let clock = Arc::new(MockClock::new());
+
+        let (progress, reporter) = ProgressBuilder::new(Task::default().total(100))
+            .clock(Arc::<MockClock>::clone(&clock))
+            .rate_smoothing(0.5)
+            .build(Arc::new(NopObserver));
+        let reporter = reporter.upgrade().unwrap();
+
+        assert_eq!(reporter.report().units_per_second(), None);
+
+        clock.advance(Duration::from_secs(1));
+        progress.set_completed(10);
+
+        assert_eq!(reporter.report().units_per_second(), Some(10.0));
+
+        clock.advance(Duration::from_secs(1));
+        progress.increment_completed_by(20);
+
+        assert_eq!(reporter.report().units_per_second(), Some(15.0));
+    }
+}
+
+mod reset {
+    use super::*;
+
+    #[test]
+    fn reset() {
+        let (spy, observer) = SpyObserver::new();
+
+        let (progress, reporter) = Progress::new(
+            Task::default().label("label").total(10).cancelable(true),
+            observer,
+        );
+
+        progress.set_completed(7);
+        progress.cancel().unwrap();
+
+        let update_events_before = spy.update_events().len();
+
+        progress.reset();
+
+        assert_eq!(spy.update_events().len(), update_events_before + 1);
+
+        let report = reporter.upgrade().unwrap().report();
+
+        assert_eq!(report.completed, 0);
+        assert_eq!(report.total, 10);
+        assert_eq!(report.state, State::Running);
+        assert_eq!(report.label.as_deref(), Some("label"));
+        assert!(report.last_change() > Generation::MIN);
+    }
+
+    #[test]
+    fn reset_with() {
+        let (spy, observer) = SpyObserver::new();
+
+        let (progress, reporter) = Progress::new(Task::default().label("old").total(10), observer);
+
+        progress.set_completed(7);
+
+        let update_events_before = spy.update_events().len();
+
+        progress.reset_with(Task::default().label("new").total(3));
+
+        assert_eq!(spy.update_events().len(), update_events_before + 1);
+
+        let report = reporter.upgrade().unwrap().report();
+
+        assert_eq!(report.completed, 0);
+        assert_eq!(report.total, 3);
+        assert_eq!(report.label.as_deref(), Some("new"));
+    }
+}
+
+mod complete {
+    use super::*;
+
+    #[test]
+    fn reported() {
+        let (progress, reporter) = Progress::new(Task::default().total(10), Arc::new(NopObserver));
+
+        progress.set_completed(4);
+        progress.complete();
+
+        let report = reporter.upgrade().unwrap().report();
+
+        assert_eq!(report.state, State::Finished);
+        assert_eq!(report.completed, 10);
+        assert_eq!(report.fraction, 1.0);
+    }
+
+    #[test]
+    fn terminal() {
+        let (progress, _) = Progress::new(
+            Task::default().total(10).cancelable(true).pausable(true),
+            Arc::new(NopObserver),
+        );
+
+        progress.complete();
+
+        assert_eq!(progress.cancel(), Ok(()));
+        assert_eq!(progress.state(), State::Finished);
+
+        assert_eq!(progress.pause(), Ok(()));
+        assert_eq!(progress.state(), State::Finished);
+
+        assert_eq!(progress.resume(), Ok(()));
+        assert_eq!(progress.state(), State::Finished);
+    }
+}
+
+mod control {
+    use super::*;
+
+    #[test]
+    fn on_state_change() {
+        let task = Task::default().pausable(true).cancelable(true);
+
+        let (parent, _) = Progress::new(task.clone(), Arc::new(NopObserver));
+        let children: Vec<_> = (0..2)
+            .map(|_| Progress::new_with_parent(task.clone(), &parent))
+            .collect();
+
+        let transitions = Arc::new(Mutex::new(vec![]));
+
+        for progress in std::iter::once(&parent).chain(&children) {
+            let transitions = Arc::clone(&transitions);
+            let id = progress.id();
+
+            progress.on_state_change(move |state| transitions.lock().unwrap().push((id, state)));
+        }
+
+        let count = |state: State| {
+            transitions
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(_, s)| *s == state)
+                .count()
+        };
+
+        parent.pause().unwrap();
+        assert_eq!(count(State::Paused), 3);
+
+        // No-op transitions don't invoke the callback:
+        parent.pause().unwrap();
+        assert_eq!(count(State::Paused), 3);
+
+        parent.cancel().unwrap();
+        assert_eq!(count(State::Canceled), 3);
+
+        assert_eq!(transitions.lock().unwrap().len(), 6);
+    }
+
+    #[test]
+    fn on_state_change_updating_progress() {
+        let task = Task::default().pausable(true);
+
+        let (progress, _) = Progress::new(task, Arc::new(NopObserver));
+
+        let weak_progress = Arc::downgrade(&progress);
+
+        progress.on_state_change(move |state| {
+            let progress = weak_progress.upgrade().unwrap();
+
+            progress.set_label(Cow::Owned(format!("{state:?}")));
+            progress.info(|| "state changed");
+        });
+
+        progress.pause().unwrap();
+        assert_eq!(progress.label().as_deref(), Some("Paused"));
+
+        progress.set_state(State::Running);
+        assert_eq!(progress.label().as_deref(), Some("Running"));
+
+        progress.update_batch([|task: &mut Task| task.state = State::Finished]);
+        assert_eq!(progress.label().as_deref(), Some("Finished"));
+    }
+
+    #[test]
+    fn task_builder_flags() {
+        let task = Task::default()
+            .label("label")
+            .total(10)
+            .state(State::Paused)
+            .pausable(true)
+            .cancelable(true);
+
+        let (progress, reporter) = Progress::new(task, Arc::new(NopObserver));
+
+        assert!(progress.is_pausable());
+        assert!(progress.is_cancelable());
+        assert!(progress.is_paused());
+
+        let report = reporter.upgrade().unwrap().report();
+
+        assert_eq!(report.label.as_deref(), Some("label"));
+        assert_eq!(report.total, 10);
+        assert_eq!(report.state, State::Paused);
+
+        let task = Task::default().pausable(true).pausable(false);
+
+        assert!(!task.is_pausable);
+    }
+
+    #[test]
+    fn not_pausable() {
+        let (progress, _) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        assert_eq!(progress.pause(), Err(ControlError::NotPausable));
+        assert_eq!(progress.resume(), Err(ControlError::NotPausable));
+        assert_eq!(progress.state(), State::Running);
+    }
+
+    #[test]
+    fn not_cancelable() {
+        let (progress, _) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        assert_eq!(progress.cancel(), Err(ControlError::NotCancelable));
+        assert_eq!(progress.state(), State::Running);
+    }
+
+    #[test]
+    fn not_cancelable_child() {
+        let (parent, _) = Progress::new(Task::default().cancelable(true), Arc::new(NopObserver));
+        let child = Progress::new_with_parent(Task::default(), &parent);
+
+        assert_eq!(parent.cancel(), Err(ControlError::NotCancelable));
+        assert_eq!(parent.state(), State::Canceled);
+        assert_eq!(child.state(), State::Running);
+    }
+
+    #[test]
+    fn pause_and_resume() {
+        let (parent, _) = Progress::new(Task::default().pausable(true), Arc::new(NopObserver));
+        let child = Progress::new_with_parent(Task::default().pausable(true), &parent);
+
+        assert_eq!(parent.pause(), Ok(()));
+        assert_eq!(child.state(), State::Paused);
+
+        assert_eq!(parent.resume(), Ok(()));
+        assert_eq!(child.state(), State::Running);
+    }
+
+    #[test]
+    fn subtree() {
+        let task = Task::default().pausable(true).cancelable(true);
+
+        let (root, controller) = Progress::new(task.clone(), Arc::new(NopObserver));
+        let controller = controller.upgrade().unwrap();
+
+        let branch = Progress::new_with_parent(task.clone(), &root);
+        let leaf = Progress::new_with_parent(task.clone(), &branch);
+        let sibling = Progress::new_with_parent(task.clone(), &root);
+
+        assert_eq!(controller.pause_subtree(branch.id()), Ok(()));
+        assert_eq!(
+            [root.state(), branch.state(), leaf.state(), sibling.state()],
+            [State::Running, State::Paused, State::Paused, State::Running]
+        );
+
+        assert_eq!(controller.resume_subtree(leaf.id()), Ok(()));
+        assert_eq!(
+            [branch.state(), leaf.state()],
+            [State::Paused, State::Running]
+        );
+
+        assert_eq!(controller.cancel_subtree(branch.id()), Ok(()));
+        assert_eq!(
+            [root.state(), branch.state(), leaf.state(), sibling.state()],
+            [
+                State::Running,
+                State::Canceled,
+                State::Canceled,
+                State::Running
+            ]
+        );
+    }
+
+    #[test]
+    fn non_recursive() {
+        let task = Task::default().pausable(true).cancelable(true);
+
+        let (parent, reporter) = Progress::new(task.clone(), Arc::new(NopObserver));
+        let reporter = reporter.upgrade().unwrap();
+
+        let child = Progress::new_with_parent(task.clone().total(2), &parent);
+
+        assert_eq!(parent.pause_self(), Ok(()));
+        assert_eq!(
+            [parent.state(), child.state()],
+            [State::Paused, State::Running]
+        );
+
+        child.set_completed(1);
+
+        let report = reporter.report();
+
+        assert_eq!(report.state, State::Paused);
+        assert_eq!(report.completed, 1);
+        assert_eq!(report.subreports[0].state, State::Running);
+
+        assert_eq!(parent.resume_self(), Ok(()));
+        assert_eq!(
+            [parent.state(), child.state()],
+            [State::Running, State::Running]
+        );
+
+        assert_eq!(parent.cancel_self(), Ok(()));
+        assert_eq!(
+            [parent.state(), child.state()],
+            [State::Canceled, State::Running]
+        );
+
+        let (progress, _) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        assert_eq!(progress.pause_self(), Err(ControlError::NotPausable));
+        assert_eq!(progress.resume_self(), Err(ControlError::NotPausable));
+        assert_eq!(progress.cancel_self(), Err(ControlError::NotCancelable));
+    }
+
+    #[test]
+    fn subtree_errors() {
+        let (root, controller) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let controller = controller.upgrade().unwrap();
+
+        let child = Progress::new_with_parent(Task::default(), &root);
+
+        assert_eq!(
+            controller.cancel_subtree(child.id()),
+            Err(ControlError::NotCancelable)
+        );
+        assert_eq!(
+            controller.pause_subtree(child.id()),
+            Err(ControlError::NotPausable)
+        );
+        assert_eq!(
+            controller.resume_subtree(ProgressId::new_unique()),
+            Err(ControlError::NotFound)
+        );
+        assert_eq!(child.state(), State::Running);
+    }
+}
+
+mod resume_from {
+    use super::*;
+
+    #[test]
+    fn canceled() {
+        let (spy, observer) = SpyObserver::new();
+
+        let (progress, _) = Progress::new(Task::default().total(100).cancelable(true), observer);
+
+        progress.set_completed(30);
+        progress.cancel().unwrap();
+
+        let update_events_before = spy.update_events().len();
+
+        progress.resume_from(30);
+
+        assert_eq!(progress.state(), State::Running);
+        assert_eq!(progress.completed(), 30);
+        assert_eq!(progress.total(), 100);
+        assert_eq!(spy.update_events().len(), update_events_before + 1);
+    }
+}
+
+mod drive_results {
+    use super::*;
+
+    #[test]
+    fn mixed() {
+        let (spy, observer) = SpyObserver::new();
+
+        let (progress, reporter) = Progress::new(Task::default().total(5), observer);
+
+        let results = vec![Ok(1), Err("first"), Ok(2), Ok(3), Err("second")];
+
+        let oks: Vec<i32> = progress
+            .drive_results(results)
+            .filter_map(Result::ok)
+            .collect();
+
+        assert_eq!(oks, vec![1, 2, 3]);
+
+        let report = reporter.upgrade().unwrap().report();
+
+        assert_eq!(report.completed, 5);
+        assert_eq!(report.failures, 2);
+
+        let messages: Vec<_> = spy
+            .message_events()
+            .into_iter()
+            .map(|event| (event.message.into_owned(), event.priority))
+            .collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                ("first".to_owned(), PriorityLevel::Error),
+                ("second".to_owned(), PriorityLevel::Error)
+            ]
+        );
+    }
+
+    #[test]
+    fn saturating() {
+        let (progress, _reporter) =
+            Progress::new(Task::default().completed(usize::MAX), Arc::new(NopObserver));
+
+        progress.update(|task| task.failures = usize::MAX);
+
+        let results: Vec<Result<(), &str>> = vec![Err("failure")];
+
+        assert_eq!(progress.drive_results(results).count(), 1);
+
+        assert_eq!(progress.completed(), usize::MAX);
+        assert_eq!(progress.failures(), usize::MAX);
+    }
+}
+
+mod on_root_drop {
+    use super::*;
+
+    #[test]
+    fn keep_running() {
+        let (root, _) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let child = Progress::new_with_parent(Task::default(), &root);
+
+        drop(root);
+
+        assert_eq!(child.state(), State::Running);
+    }
+
+    #[test]
+    fn cancel_subtree() {
+        let (root, _) = ProgressBuilder::new(Task::default())
+            .on_root_drop(OnRootDrop::CancelSubtree)
+            .build(Arc::new(NopObserver));
+        let child = Progress::new_with_parent(Task::default(), &root);
+        let grandchild = Progress::new_with_parent(Task::default(), &child);
+
+        drop(root);
+
+        assert_eq!(child.state(), State::Canceled);
+        assert_eq!(grandchild.state(), State::Canceled);
+    }
+}
+
+mod weight {
+    use super::*;
+
+    fn make_tree(weight: f64) -> (Arc<Progress>, Arc<impl Reporter>, Vec<Arc<Progress>>) {
+        let (parent, reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        let children = vec![
+            Progress::new_with_parent(
+                Task::default().completed(1).total(2).weight(weight),
+                &parent,
+            ),
+            Progress::new_with_parent(Task::default().total(100), &parent),
+        ];
+
+        (parent, reporter.upgrade().unwrap(), children)
+    }
+
+    #[test]
+    fn unweighted() {
+        let (_parent, reporter, _children) = make_tree(1.0);
+
+        let report = reporter.report();
+
+        // 1 / (2 + 100):
+        assert_eq!(report.fraction, 1.0 / 102.0);
+        assert_eq!(report.weight_completed, None);
+    }
+
+    #[test]
+    fn weighted() {
+        let (_parent, reporter, children) = make_tree(3.0);
+
+        let report = reporter.report();
+
+        // (3.0 * 0.5 + 1.0 * 0.0) / (3.0 + 1.0):
+        assert_eq!(report.fraction, 0.375);
+
+        // The unit counts remain unscaled:
+        assert_eq!(report.completed, 1);
+        assert_eq!(report.total, 102);
+        assert_eq!(report.weight_completed, None);
+        assert_eq!(report.weight_total, None);
+
+        // A task's own weight doesn't affect its own fraction:
+        let subreport = report
+            .subreports
+            .iter()
+            .find(|report| report.progress_id == children[0].id())
+            .unwrap();
+        assert_eq!(subreport.fraction, 0.5);
+        assert_eq!(subreport.weight, 3.0);
+
+        assert_eq!(reporter.health().fraction, 0.375);
+
+        // The weighted child gets pruned, but still contributes its weighted fraction:
+        children[1].update(|_| {});
+        assert_eq!(
+            reporter.partial_report(Generation::MIN).unwrap().fraction,
+            0.375
+        );
+    }
+}
+
+mod unit_scale {
+    use super::*;
+
+    #[test]
+    fn format_progress() {
+        let (progress, reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        progress.set_unit_scale(1024.0);
+        progress.set_unit_suffix(Some("KiB".into()));
+        progress.set_total(4096);
+        progress.set_completed(2048);
+
+        let report = reporter.upgrade().unwrap().report();
+
+        assert_eq!(report.completed, 2048);
+        assert_eq!(report.total, 4096);
+        assert_eq!(report.format_progress(), "2.0 KiB / 4.0 KiB");
+    }
+}
+
+mod debug {
+    use super::*;
+
+    #[test]
+    fn fmt() {
+        let observer = Arc::new(NopObserver);
+
+        let (progress, _) = ProgressBuilder::new(Task::default())
+            .clock(Arc::new(MockClock::new()))
+            .build(observer);
+
+        let id = progress.id();
+        let report = Report {
+            progress_id: id,
+            label: None,
+            completed: 0,
+            total: 0,
+            weight_completed: None,
+            weight_total: None,
+            fraction: 0.0,
+            is_indeterminate: true,
+            state: State::Running,
+            failures: 0,
+            highest_priority_seen: None,
+            subreports: vec![],
+            collapsed: false,
+            weight: 1.0,
+            unit_scale: 1.0,
+            unit_suffix: None,
+            descendant_count: 0,
+            last_change: Generation(0),
+            timings: Timings::default(),
+        };
+
+        let actual = format!("{progress:?}");
+        let expected =
             format!("Progress {{ id: {id:?}, parent: None, children: [], report: {report:?} }}");
 
-        assert_eq!(actual, expected);
+        assert_eq!(actual, expected);
+    }
+}
+
+mod snapshot {
+    use super::*;
+
+    #[test]
+    fn stand_alone() {
+        let (progress, _weak_reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        progress.update(|task| {
+            task.label = Some("label".into());
+            task.completed = 5;
+            task.total = 10;
+        });
+
+        let report = progress.snapshot();
+
+        assert_eq!(report.progress_id, progress.id);
+        assert_eq!(report.label.as_deref(), Some("label"));
+        assert_eq!(report.completed, 5);
+        assert_eq!(report.total, 10);
+        assert_eq!(report.fraction, 0.5);
+        assert_eq!(report.subreports, vec![]);
+    }
+}
+
+mod report {
+    use super::*;
+
+    #[test]
+    fn stand_alone() {
+        let (_observer, erased_observer) = SpyObserver::new();
+
+        let (progress, weak_reporter) = Progress::new(Task::default(), erased_observer);
+
+        progress.update(|task| {
+            task.label = Some("label".into());
+            task.completed = 5;
+            task.total = 10;
+        });
+
+        let reporter = weak_reporter.upgrade().unwrap();
+
+        let report = reporter.report();
+
+        assert_eq!(report.progress_id, progress.id);
+        assert_eq!(report.label.as_deref(), Some("label"));
+        assert_eq!(report.completed, 5);
+        assert_eq!(report.total, 10);
+        assert_eq!(report.fraction, 0.5);
+        assert_eq!(report.subreports, vec![]);
+    }
+
+    #[test]
+    fn last_change() {
+        let (parent, weak_reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let child = Progress::new_with_parent(Task::default(), &parent);
+        let grandchild = Progress::new_with_parent(Task::default(), &child);
+        let _sibling = Progress::new_with_parent(Task::default(), &parent);
+
+        let reporter = weak_reporter.upgrade().unwrap();
+
+        let before = reporter.report().last_change();
+
+        grandchild.set_completed(1);
+
+        let report = reporter.report();
+
+        let max_last_change = report.iter().map(Report::last_change).max().unwrap();
+
+        assert!(report.last_change() > before);
+        assert_eq!(report.last_change(), max_last_change);
+        assert_eq!(
+            report.find(grandchild.id()).unwrap().last_change(),
+            max_last_change
+        );
     }
-}
 
-mod report {
-    use super::*;
+    #[test]
+    fn weighted() {
+        let (parent, weak_reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let discrete_child = Progress::new_with_parent(Task::default().total(10), &parent);
+        let weighted_child = Progress::new_with_parent(Task::default(), &parent);
+
+        discrete_child.set_completed(5);
+        weighted_child.set_weight_total(2.0);
+        weighted_child.set_weight_completed(1.5);
+
+        let report = weak_reporter.upgrade().unwrap().report();
+
+        assert_eq!(report.completed, 5);
+        assert_eq!(report.total, 10);
+        assert_eq!(report.weight_completed, Some(6.5));
+        assert_eq!(report.weight_total, Some(12.0));
+        assert_eq!(report.fraction, 6.5 / 12.0);
+        assert!(!report.is_indeterminate);
+
+        let discrete_report = report
+            .subreports
+            .iter()
+            .find(|report| report.progress_id == discrete_child.id())
+            .unwrap();
+
+        assert_eq!(discrete_report.weight_completed, None);
+        assert_eq!(discrete_report.fraction, 0.5);
+
+        let weighted_report = report
+            .subreports
+            .iter()
+            .find(|report| report.progress_id == weighted_child.id())
+            .unwrap();
+
+        assert_eq!(weighted_report.weight_completed, Some(1.5));
+        assert_eq!(weighted_report.fraction, 0.75);
+    }
 
     #[test]
-    fn stand_alone() {
-        let (_observer, erased_observer) = SpyObserver::new();
+    fn excluding() {
+        let (parent, weak_reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let collapsed_child = Progress::new_with_parent(Task::default().total(2), &parent);
+        let expanded_child = Progress::new_with_parent(Task::default().total(2), &parent);
+
+        for child in [&collapsed_child, &expanded_child] {
+            for _ in 0..2 {
+                Progress::new_with_parent(Task::default().completed(1).total(1), child);
+            }
+        }
 
-        let (progress, weak_reporter) = Progress::new(Task::default(), erased_observer);
+        let collapsed = HashSet::from([collapsed_child.id()]);
+
+        let report = weak_reporter
+            .upgrade()
+            .unwrap()
+            .report_excluding(&collapsed);
+
+        assert_eq!(report.completed, 4);
+        assert_eq!(report.total, 8);
+        assert!(!report.collapsed);
+
+        let collapsed_report = report
+            .subreports
+            .iter()
+            .find(|report| report.progress_id == collapsed_child.id())
+            .unwrap();
+
+        assert!(collapsed_report.collapsed);
+        assert_eq!(collapsed_report.completed, 2);
+        assert_eq!(collapsed_report.total, 4);
+        assert_eq!(collapsed_report.fraction, 0.5);
+        assert_eq!(collapsed_report.subreports, vec![]);
+
+        let expanded_report = report
+            .subreports
+            .iter()
+            .find(|report| report.progress_id == expanded_child.id())
+            .unwrap();
+
+        assert!(!expanded_report.collapsed);
+        assert_eq!(expanded_report.subreports.len(), 2);
+    }
 
-        progress.update(|task| {
-            task.label = Some("label".into());
-            task.completed = 5;
-            task.total = 10;
-        });
+    #[test]
+    fn to_depth() {
+        let (progresses, weak_reporter) = crate::test_utils::make_hierarchy();
+
+        for progress in progresses.iter().skip(1) {
+            progress.update(|task| {
+                task.completed = 1;
+                task.total = 2;
+            });
+        }
 
         let reporter = weak_reporter.upgrade().unwrap();
 
+        let report = reporter.report_to_depth(0);
+
+        assert!(report.collapsed);
+        assert_eq!(report.completed, 110);
+        assert_eq!(report.total, 220);
+        assert_eq!(report.subreports.len(), 0);
+
+        let report = reporter.report_to_depth(1);
+
+        assert!(!report.collapsed);
+        assert_eq!(report.completed, 110);
+        assert_eq!(report.total, 220);
+        assert_eq!(report.subreports.len(), 10);
+
+        for subreport in &report.subreports {
+            assert!(subreport.collapsed);
+            assert_eq!(subreport.completed, 11);
+            assert_eq!(subreport.total, 22);
+            assert_eq!(subreport.subreports.len(), 0);
+        }
+
+        let report = reporter.report_to_depth(2);
+
+        assert_eq!(report.subreports.len(), 10);
+
+        for subreport in &report.subreports {
+            assert!(!subreport.collapsed);
+            assert_eq!(subreport.subreports.len(), 10);
+        }
+    }
+
+    #[test]
+    fn child_counts() {
+        let (_progresses, weak_reporter) = crate::test_utils::make_hierarchy();
+
+        let report = weak_reporter.upgrade().unwrap().report();
+
+        assert_eq!(report.direct_child_count(), 10);
+        assert_eq!(report.descendant_count(), 110);
+
+        for subreport in &report.subreports {
+            assert_eq!(subreport.direct_child_count(), 10);
+            assert_eq!(subreport.descendant_count(), 10);
+        }
+    }
+
+    #[test]
+    fn descendant_count_field() {
+        let (progresses, weak_reporter) = crate::test_utils::make_hierarchy();
+
+        let reporter = weak_reporter.upgrade().unwrap();
         let report = reporter.report();
 
-        assert_eq!(report.progress_id, progress.id);
-        assert_eq!(report.label.unwrap(), "label");
-        assert_eq!(report.completed, 5);
-        assert_eq!(report.total, 10);
-        assert_eq!(report.fraction, 0.5);
-        assert_eq!(report.subreports, vec![]);
+        assert_eq!(report.descendant_count, 110);
+        assert_eq!(report.descendant_count, report.iter().count() - 1);
+
+        // Pruned reports only count their remaining descendants:
+        let generation = report.last_change;
+        progresses[1].update(|_| {});
+
+        let partial_report = reporter.partial_report(generation).unwrap();
+        assert_eq!(partial_report.descendant_count, 1);
     }
 
     #[test]
@@ -401,7 +2533,7 @@ mod report {
         let parent_report = reporter.report();
 
         assert_eq!(parent_report.progress_id, parent.id);
-        assert_eq!(parent_report.label.unwrap(), "parent");
+        assert_eq!(parent_report.label.as_deref(), Some("parent"));
         assert_eq!(parent_report.completed, 3);
         assert_eq!(parent_report.total, 6);
         assert_eq!(parent_report.fraction, 0.5);
@@ -410,7 +2542,7 @@ mod report {
         let child_report = parent_report.subreports[0].clone();
 
         assert_eq!(child_report.progress_id, child.id);
-        assert_eq!(child_report.label.unwrap(), "child");
+        assert_eq!(child_report.label.as_deref(), Some("child"));
         assert_eq!(child_report.completed, 2);
         assert_eq!(child_report.total, 4);
         assert_eq!(child_report.fraction, 0.5);
@@ -419,12 +2551,386 @@ mod report {
         let grandchild_report = child_report.subreports[0].clone();
 
         assert_eq!(grandchild_report.progress_id, grandchild.id);
-        assert_eq!(grandchild_report.label.unwrap(), "grandchild");
+        assert_eq!(grandchild_report.label.as_deref(), Some("grandchild"));
         assert_eq!(grandchild_report.completed, 1);
         assert_eq!(grandchild_report.total, 2);
         assert_eq!(grandchild_report.fraction, 0.5);
         assert_eq!(grandchild_report.subreports.len(), 0);
     }
+
+    #[test]
+    fn deterministic_order() {
+        let (parent, weak_reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        let children: Vec<_> = (0..20)
+            .map(|_| Progress::new_with_parent(Task::default(), &parent))
+            .collect();
+        let child_ids: Vec<_> = children.iter().map(|child| child.id()).collect();
+
+        let reporter = weak_reporter.upgrade().unwrap();
+
+        for _ in 0..10 {
+            let report = reporter.report();
+            let ids: Vec<_> = report
+                .subreports
+                .iter()
+                .map(|report| report.progress_id)
+                .collect();
+
+            assert_eq!(ids, child_ids);
+        }
+
+        let baseline = reporter.report().last_change();
+
+        children[7].set_completed(1);
+        children[3].set_completed(1);
+
+        let report = reporter.partial_report(baseline).unwrap();
+        let ids: Vec<_> = report
+            .subreports
+            .iter()
+            .map(|report| report.progress_id)
+            .collect();
+
+        assert_eq!(ids, [child_ids[3], child_ids[7]]);
+    }
+
+    #[test]
+    fn label_path() {
+        let (parent, weak_reporter) =
+            Progress::new(Task::default().label("parent"), Arc::new(NopObserver));
+        let child = Progress::new_with_parent(Task::default().label("child"), &parent);
+        let unlabeled = Progress::new_with_parent(Task::default(), &child);
+        let grandchild = Progress::new_with_parent(Task::default().label("grandchild"), &unlabeled);
+
+        assert_eq!(parent.label_path(" > "), "parent");
+        assert_eq!(child.label_path(" > "), "parent > child");
+        assert_eq!(unlabeled.label_path(" > "), "parent > child");
+        assert_eq!(grandchild.label_path(" > "), "parent > child > grandchild");
+
+        let report = weak_reporter.upgrade().unwrap().report();
+
+        assert_eq!(
+            report.label_path(parent.id(), " > ").as_deref(),
+            Some("parent")
+        );
+        assert_eq!(
+            report.label_path(unlabeled.id(), " > ").as_deref(),
+            Some("parent > child")
+        );
+        assert_eq!(
+            report.label_path(grandchild.id(), "/").as_deref(),
+            Some("parent/child/grandchild")
+        );
+        assert_eq!(report.label_path(ProgressId::new_unique(), " > "), None);
+
+        child.set_label(None);
+
+        assert_eq!(grandchild.label_path(" > "), "parent > grandchild");
+    }
+}
+
+mod partial_report {
+    use super::*;
+
+    #[test]
+    fn aggregates_pruned_children() {
+        let (parent, weak_reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        let children: Vec<_> = (0..3)
+            .map(|_| {
+                let child = Progress::new_with_parent(Task::default().total(10), &parent);
+                let _grandchild =
+                    Progress::new_with_parent(Task::default().completed(10).total(10), &child);
+                child
+            })
+            .collect();
+
+        children[0].set_completed(5);
+        children[1].set_completed(5);
+
+        let reporter = weak_reporter.upgrade().unwrap();
+
+        let baseline = reporter.report().last_change();
+
+        children[2].set_completed(5);
+
+        let report = reporter.partial_report(baseline).unwrap();
+
+        assert_eq!(report.subreports.len(), 1);
+        assert_eq!(report.subreports[0].progress_id, children[2].id());
+        assert_eq!(report.completed, 45);
+        assert_eq!(report.total, 60);
+        assert_eq!(report.fraction, 0.75);
+    }
+
+    #[test]
+    fn stand_alone() {
+        let (progress, reporter) = test_utils::make_stand_alone(None);
+        let reporter = reporter.upgrade().unwrap();
+
+        progress.set_total(3);
+
+        let mut last_change = Generation::MIN;
+
+        for completed in 1..=3 {
+            progress.set_completed(completed);
+
+            let report = reporter.partial_report(last_change).unwrap();
+
+            assert_eq!(report.completed, completed);
+            assert!(report.last_change() > last_change);
+
+            last_change = report.last_change();
+        }
+
+        assert_eq!(reporter.partial_report(last_change), None);
+    }
+
+    #[test]
+    fn hierarchical() {
+        let (progresses, reporter) = test_utils::make_hierarchy();
+        let reporter = reporter.upgrade().unwrap();
+
+        let mut last_change = reporter.report().last_change();
+
+        assert_eq!(reporter.partial_report(last_change), None);
+
+        // Indices of the root's first child and its third grandchild:
+        let (child, grandchild) = (&progresses[1], &progresses[4]);
+
+        grandchild.set_completed(1);
+
+        let report = reporter.partial_report(last_change).unwrap();
+
+        let ids: Vec<_> = report.iter().map(|report| report.progress_id).collect();
+
+        assert_eq!(ids, [progresses[0].id(), child.id(), grandchild.id()]);
+
+        last_change = report.last_change();
+
+        child.set_completed(1);
+
+        let report = reporter.partial_report(last_change).unwrap();
+
+        let ids: Vec<_> = report.iter().map(|report| report.progress_id).collect();
+
+        assert_eq!(ids, [progresses[0].id(), child.id()]);
+    }
+}
+
+#[test]
+fn health() {
+    let (parent, weak_reporter) = Progress::new(Task::default().total(4), Arc::new(NopObserver));
+
+    let states = [
+        State::Running,
+        State::Paused,
+        State::Canceled,
+        State::Finished,
+    ];
+
+    let children: Vec<_> = states
+        .into_iter()
+        .map(|state| {
+            let child = Progress::new_with_parent(Task::default().completed(1).total(1), &parent);
+            child.set_state(state);
+            child
+        })
+        .collect();
+
+    children[1].warn(|| "warning");
+    children[2].error(|| "error");
+
+    let grandchild = Progress::new_with_parent(Task::default(), &parent.children().next().unwrap());
+    grandchild.set_state(State::Paused);
+
+    let health = weak_reporter.upgrade().unwrap().health();
+
+    assert_eq!(
+        health,
+        TreeHealth {
+            nodes: 6,
+            running: 2,
+            paused: 2,
+            finished: 1,
+            canceled: 1,
+            fraction: 0.5,
+            max_priority: Some(PriorityLevel::Error),
+        }
+    );
+}
+
+#[test]
+fn sampled() {
+    let (progress, weak_reporter) = Progress::new(Task::default().total(3), Arc::new(NopObserver));
+
+    let interval = Duration::from_millis(10);
+
+    let mut samples = weak_reporter.upgrade().unwrap().sampled(interval);
+
+    let start = std::time::Instant::now();
+
+    for completed in 1..=3 {
+        progress.set_completed(completed);
+
+        let report = samples.next().unwrap();
+
+        assert_eq!(report.completed, completed);
+    }
+
+    assert!(start.elapsed() >= 3 * interval);
+
+    drop(progress);
+
+    assert!(samples.next().is_none());
+}
+
+#[test]
+fn inactive_observer() {
+    struct InactiveObserver;
+
+    impl Observer for InactiveObserver {
+        fn observe(&self, event: Event) {
+            panic!("unexpected event: {event:?}");
+        }
+
+        fn is_active(&self) -> bool {
+            false
+        }
+    }
+
+    let (parent, reporter) = Progress::new(Task::default(), Arc::new(InactiveObserver));
+    let child = Progress::new_with_parent(Task::default().total(2), &parent);
+
+    // Make the next update overflow the tree's generation:
+    parent
+        .atomic_state
+        .last_change
+        .swap(Generation::MAX, Ordering::Relaxed);
+
+    child.set_completed(1);
+    child.info(|| "message");
+    child.update_batch([|task: &mut Task| task.completed = 2]);
+    child.set_state(State::Paused);
+    child.detach_from_parent(Arc::new(NopObserver));
+
+    assert_eq!(reporter.upgrade().unwrap().report().discrete(), (0, 0));
+    assert_eq!(child.report().discrete(), (2, 2));
+}
+
+#[test]
+fn combine() {
+    let (first_observer, first_erased_observer) = SpyObserver::new();
+    let (second_observer, second_erased_observer) = SpyObserver::new();
+    let (observer, erased_observer) = SpyObserver::new();
+
+    let (first, _first_reporter) = Progress::new(Task::default().total(2), first_erased_observer);
+    let first_child = Progress::new_with_parent(Task::default().total(2), &first);
+    let (second, _second_reporter) =
+        Progress::new(Task::default().total(4), second_erased_observer);
+    let second_child = Progress::new_with_parent(Task::default().total(4), &second);
+
+    first_child.set_completed(2);
+    second_child.set_completed(1);
+
+    let first_events_len = first_observer.events_len();
+    let second_events_len = second_observer.events_len();
+
+    let (root, reporter) = super::combine(
+        vec![Arc::clone(&first), Arc::clone(&second)],
+        Task::default().label("combined"),
+        erased_observer,
+    );
+
+    let report = reporter.upgrade().unwrap().report();
+
+    assert_eq!(report.progress_id, root.id());
+    assert_eq!(report.label.as_deref(), Some("combined"));
+    assert_eq!(report.discrete(), (3, 12));
+    assert_eq!(report.descendant_count(), 4);
+    assert_eq!(
+        report
+            .subreports
+            .iter()
+            .map(|report| report.progress_id)
+            .collect::<Vec<_>>(),
+        vec![first.id(), second.id()]
+    );
+
+    // Any further events of the combined tree go to the new observer:
+    let baseline = report.last_change();
+    let events_len = observer.events_len();
+
+    first.increment_completed();
+    second_child.increment_completed();
+
+    assert_eq!(first_observer.events_len(), first_events_len);
+    assert_eq!(second_observer.events_len(), second_events_len);
+    assert_eq!(observer.events_len(), events_len + 2);
+
+    let partial_report = root.partial_report(baseline).unwrap();
+
+    assert_eq!(partial_report.discrete(), (5, 12));
+}
+
+mod visit {
+    use super::*;
+
+    #[test]
+    fn counts_nodes() {
+        let (progresses, _weak_reporter) = test_utils::make_hierarchy();
+
+        let mut count = 0;
+        progresses[0].visit(&mut |_| count += 1);
+
+        assert_eq!(count, 111);
+    }
+
+    #[test]
+    fn pre_order() {
+        let (parent, _weak_reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let child = Progress::new_with_parent(Task::default(), &parent);
+        let grandchild = Progress::new_with_parent(Task::default(), &child);
+        let sibling = Progress::new_with_parent(Task::default(), &parent);
+
+        let mut ids = vec![];
+        parent.visit(&mut |progress| ids.push(progress.id()));
+
+        assert_eq!(
+            ids,
+            vec![parent.id(), child.id(), grandchild.id(), sibling.id()]
+        );
+    }
+
+    #[test]
+    fn reentrant() {
+        let (parent, _weak_reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+        let _child = Progress::new_with_parent(Task::default().total(1), &parent);
+
+        parent.visit(&mut |progress| {
+            progress.set_completed(progress.total());
+            let _ = progress.children().count();
+        });
+
+        assert_eq!(parent.report().completed, 1);
+    }
+}
+
+#[test]
+fn explicit_ids() {
+    let parent_id = ProgressId::from_raw(usize::MAX - 1);
+    let child_id = ProgressId::from_raw(usize::MAX);
+
+    let (parent, _reporter) =
+        Progress::new_with_explicit_id(parent_id, Task::default(), Arc::new(NopObserver));
+    let child = Progress::new_with_parent_and_explicit_id(child_id, Task::default(), &parent);
+
+    assert_eq!(parent.id(), parent_id);
+    assert_eq!(child.id(), child_id);
+
+    assert_eq!(parent.get(parent_id).unwrap().id(), parent_id);
+    assert_eq!(parent.get(child_id).unwrap().id(), child_id);
 }
 
 #[test]
@@ -435,7 +2941,7 @@ fn get() {
     let child = Progress::new_with_parent(Task::default(), &parent);
     let grandchild = Progress::new_with_parent(Task::default(), &child);
 
-    let missing_id = ProgressId(42);
+    let missing_id = ProgressId(usize::MAX);
 
     assert!(parent.get(missing_id).is_none());
     assert_eq!(parent.get(parent.id).unwrap().id, parent.id);
@@ -452,3 +2958,211 @@ fn get() {
     assert!(grandchild.get(child.id).is_none());
     assert_eq!(grandchild.get(grandchild.id).unwrap().id, grandchild.id);
 }
+
+#[test]
+fn get_by_raw() {
+    let (parent, _weak_reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+    let child = Progress::new_with_parent(Task::default(), &parent);
+    let grandchild = Progress::new_with_parent(Task::default(), &child);
+
+    for progress in [&parent, &child, &grandchild] {
+        let raw = progress.id().as_raw();
+
+        assert_eq!(ProgressId::from_raw(raw), progress.id());
+        assert_eq!(parent.get_by_raw(raw).unwrap().id(), progress.id());
+    }
+
+    assert!(parent.get_by_raw(usize::MAX).is_none());
+    assert!(grandchild.get_by_raw(parent.id().as_raw()).is_none());
+}
+
+mod cancellation_token {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn wait_released_by_cancel() {
+        let (progress, _reporter) =
+            Progress::new(Task::default().cancelable(true), Arc::new(NopObserver));
+        let child = Progress::new_with_parent(Task::default().cancelable(true), &progress);
+
+        let token = progress.cancellation_token();
+        let child_token = child.cancellation_token();
+
+        assert!(!token.is_canceled());
+
+        let waiter = {
+            let token = token.clone();
+            thread::spawn(move || token.wait())
+        };
+
+        let canceler = {
+            let progress = Arc::clone(&progress);
+            thread::spawn(move || progress.cancel().unwrap())
+        };
+
+        canceler.join().unwrap();
+        waiter.join().unwrap();
+
+        assert!(token.is_canceled());
+        assert!(child_token.is_canceled());
+    }
+
+    #[test]
+    fn canceled_via_update() {
+        let (progress, _reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+
+        let token = progress.cancellation_token();
+
+        progress.update(|task| task.state = State::Canceled);
+
+        assert!(token.is_canceled());
+
+        let (progress, _reporter) = Progress::new(
+            Task::default().state(State::Canceled),
+            Arc::new(NopObserver),
+        );
+
+        assert!(progress.cancellation_token().is_canceled());
+    }
+
+    #[test]
+    fn fresh_after_reset() {
+        let (progress, _reporter) =
+            Progress::new(Task::default().cancelable(true), Arc::new(NopObserver));
+
+        progress.cancel().unwrap();
+
+        let canceled_token = progress.cancellation_token();
+        assert!(canceled_token.is_canceled());
+
+        progress.reset();
+
+        assert!(!progress.cancellation_token().is_canceled());
+        // Previously obtained tokens remain signaled:
+        assert!(canceled_token.is_canceled());
+
+        progress.cancel().unwrap();
+        assert!(progress.cancellation_token().is_canceled());
+    }
+
+    #[test]
+    fn fresh_after_resume_from() {
+        let (progress, reporter) =
+            Progress::new(Task::default().cancelable(true), Arc::new(NopObserver));
+
+        progress.cancel().unwrap();
+        assert!(progress.cancellation_token().is_canceled());
+
+        reporter.upgrade().unwrap().resume_from(3);
+
+        assert_eq!(progress.state(), State::Running);
+        assert!(!progress.cancellation_token().is_canceled());
+    }
+
+    #[test]
+    fn fresh_after_batch_update() {
+        let (progress, _reporter) =
+            Progress::new(Task::default().cancelable(true), Arc::new(NopObserver));
+
+        progress.cancel().unwrap();
+
+        progress.update_batch([|task: &mut Task| task.state = State::Running]);
+
+        assert!(!progress.cancellation_token().is_canceled());
+    }
+}
+
+mod deep_tree {
+    use super::*;
+
+    const DEPTH: usize = 50_000;
+
+    fn make_chain() -> (
+        Arc<Progress>,
+        Arc<impl Reporter + Controller>,
+        Vec<Arc<Progress>>,
+    ) {
+        let task = Task::default().pausable(true).cancelable(true);
+        let (root, reporter) = Progress::new(task.clone(), Arc::new(NopObserver));
+
+        let mut leaf = Arc::clone(&root);
+        let mut chain = vec![];
+
+        for _ in 0..DEPTH {
+            let child = Progress::new_with_parent(task.clone().completed(1).total(2), &leaf);
+            chain.push(Arc::clone(&child));
+            leaf = child;
+        }
+
+        (root, reporter.upgrade().unwrap(), chain)
+    }
+
+    #[test]
+    fn report() {
+        let (_root, reporter, _chain) = make_chain();
+
+        let report = reporter.report();
+
+        assert_eq!(report.completed, DEPTH);
+        assert_eq!(report.total, 2 * DEPTH);
+        assert_eq!(report.descendant_count(), DEPTH);
+        assert_eq!(report.iter().count(), 1 + DEPTH);
+
+        // Cloning, comparing and dropping the report are iterative, too:
+        let clone = report.clone();
+        assert!(clone == report);
+
+        drop(clone);
+        drop(report);
+    }
+
+    #[test]
+    fn update_leaf() {
+        let (root, reporter, chain) = make_chain();
+
+        let leaf = chain.last().unwrap();
+        let generation = reporter.report().last_change;
+
+        leaf.increment_completed();
+
+        let partial_report = reporter.partial_report(generation).unwrap();
+        assert_eq!(partial_report.completed, DEPTH + 1);
+        assert_eq!(partial_report.iter().count(), 1 + DEPTH);
+        assert_eq!(
+            partial_report
+                .find(leaf.id())
+                .map(|report| report.completed),
+            Some(2)
+        );
+
+        let collapsed_report = reporter.report_to_depth(0);
+        assert_eq!(collapsed_report.completed, DEPTH + 1);
+        assert!(collapsed_report.collapsed);
+
+        assert_eq!(root.aggregate_discrete(), (DEPTH + 1, 2 * DEPTH));
+        assert_eq!(reporter.health().nodes, 1 + DEPTH);
+        assert!(reporter.get(leaf.id()).is_some());
+
+        let mut visited = 0;
+        root.visit(&mut |_| visited += 1);
+        assert_eq!(visited, 1 + DEPTH);
+    }
+
+    #[test]
+    fn control() {
+        let (_root, reporter, chain) = make_chain();
+
+        let leaf = chain.last().unwrap();
+
+        reporter.pause().unwrap();
+        assert_eq!(leaf.state(), State::Paused);
+
+        reporter.resume().unwrap();
+        assert_eq!(leaf.state(), State::Running);
+
+        reporter.cancel().unwrap();
+        assert_eq!(leaf.state(), State::Canceled);
+    }
+}
@@ -0,0 +1,89 @@
+//! A completion rate estimator.
+
+use std::time::Instant;
+
+/// The default smoothing factor of `RateEstimator`.
+pub(crate) const DEFAULT_SMOOTHING: f64 = 0.3;
+
+/// An estimator of a task's completion rate (in units per second),
+/// based on an exponentially-weighted moving average.
+#[derive(Clone, Debug)]
+pub(crate) struct RateEstimator {
+    smoothing: f64,
+    last_sample: (Instant, usize),
+    units_per_second: Option<f64>,
+}
+
+impl RateEstimator {
+    pub(crate) fn new(smoothing: f64, now: Instant, completed: usize) -> Self {
+        Self {
+            smoothing,
+            last_sample: (now, completed),
+            units_per_second: None,
+        }
+    }
+
+    pub(crate) fn smoothing(&self) -> f64 {
+        self.smoothing
+    }
+
+    pub(crate) fn units_per_second(&self) -> Option<f64> {
+        self.units_per_second
+    }
+
+    /// Records a sample of `completed` units at `now`.
+    ///
+    /// Samples taken at the same instant as the previous one get merged
+    /// into the next sample that has some time elapsed.
+    pub(crate) fn sample(&mut self, now: Instant, completed: usize) {
+        let (last_instant, last_completed) = self.last_sample;
+
+        if completed < last_completed {
+            // The task got reset, so we start sampling from scratch:
+            self.last_sample = (now, completed);
+            return;
+        }
+
+        let elapsed = now.saturating_duration_since(last_instant).as_secs_f64();
+
+        if elapsed <= 0.0 {
+            return;
+        }
+
+        let rate = (completed - last_completed) as f64 / elapsed;
+
+        self.units_per_second = Some(match self.units_per_second {
+            Some(average) => self.smoothing * rate + (1.0 - self.smoothing) * average,
+            None => rate,
+        });
+
+        self.last_sample = (now, completed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn smoothing() {
+        let start = Instant::now();
+        let mut estimator = RateEstimator::new(0.5, start, 0);
+
+        assert_eq!(estimator.units_per_second(), None);
+
+        estimator.sample(start + Duration::from_secs(1), 10);
+        assert_eq!(estimator.units_per_second(), Some(10.0));
+
+        // A stall only halves the estimate:
+        estimator.sample(start + Duration::from_secs(2), 10);
+        assert_eq!(estimator.units_per_second(), Some(5.0));
+
+        // Samples without any elapsed time get merged into the next one:
+        estimator.sample(start + Duration::from_secs(2), 15);
+        estimator.sample(start + Duration::from_secs(3), 20);
+        assert_eq!(estimator.units_per_second(), Some(7.5));
+    }
+}
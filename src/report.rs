@@ -1,11 +1,18 @@
 //! A progress' report.
 
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashMap, time::Duration};
 
-use crate::{generation::Generation, task::State, ProgressId};
+use crate::{
+    aggregate::Aggregate,
+    generation::Generation,
+    health::StateCounts,
+    task::{State, Task},
+    PriorityLevel, ProgressId,
+};
 
 /// A progress' report.
-#[derive(Clone, PartialEq, Default, Debug)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Report {
     /// The associated progress' identifier.
     pub progress_id: ProgressId,
@@ -17,54 +24,786 @@ pub struct Report {
     /// The number of accumulative total units of work
     /// (i.e. including sub-reports' total units).
     pub total: usize,
+    /// The number of accumulative completed weighted units
+    /// (i.e. including sub-reports' completed units),
+    /// or `None` if none of the accumulated tasks uses weighted units.
+    ///
+    /// Tasks without weighted units contribute their integer unit counts instead.
+    pub weight_completed: Option<f64>,
+    /// The number of accumulative total weighted units
+    /// (i.e. including sub-reports' total units),
+    /// or `None` if none of the accumulated tasks uses weighted units.
+    ///
+    /// Tasks without weighted units contribute their integer unit counts instead.
+    pub weight_total: Option<f64>,
     /// A fractional representation of accumulative progress
     /// (i.e. including sub-reports) within range of `0.0..=1.0`.
+    ///
+    /// If any accumulated task uses weighted units, then the fraction
+    /// is based on `weight_completed` and `weight_total`,
+    /// otherwise it is based on `completed` and `total`.
+    ///
+    /// If any of the sub-reports has a non-default `weight`, then the fraction
+    /// is the weighted mean of the sub-reports' fractions instead
+    /// (see [`Task::weight()`] for more info).
     pub fraction: f64,
     /// A boolean value that indicates whether the tracked progress is indeterminate.
     pub is_indeterminate: bool,
     /// The associated progress' state.
+    ///
+    /// This is the state of the progress' own task only,
+    /// see [`Report::aggregate_state()`] for the state of its whole tree.
     pub state: State,
+    /// The associated task's failed unit count.
+    pub failures: usize,
+    /// The highest priority level among the messages emitted by the associated progress itself
+    /// (since the last call to [`Progress::clear_messages_seen()`](crate::Progress::clear_messages_seen)),
+    /// or `None` if it hasn't emitted any.
+    ///
+    /// See [`Report::max_priority()`] for the highest priority level within its whole tree.
+    pub highest_priority_seen: Option<PriorityLevel>,
     /// The reports of the associated progress' children.
     pub subreports: Vec<Report>,
+    /// A boolean value that indicates whether the report got collapsed,
+    /// i.e. its `subreports` got omitted, while still being accounted for
+    /// in its accumulative values.
+    pub collapsed: bool,
+    /// The associated task's relative weight within its parent's aggregate.
+    ///
+    /// See [`Task::weight()`] for more info.
+    pub weight: f64,
+    /// The associated task's display unit scale.
+    pub unit_scale: f64,
+    /// The associated task's display unit suffix.
+    pub unit_suffix: Option<Cow<'static, str>>,
+    /// The total number of descendants (excluding `self`),
+    /// i.e. the number of reports nested within `subreports`, recursively.
+    ///
+    /// The count gets computed upon generating (or pruning) the report,
+    /// hence it doesn't reflect any subsequent modifications of `subreports`.
+    pub descendant_count: usize,
 
     /// The generation at which the associated task,
     /// or any of its sub-tasks, were most recently changed.
     pub(crate) last_change: Generation,
+
+    /// The associated progress' time measurements.
+    pub(crate) timings: Timings,
+}
+
+/// A report's time measurements.
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Timings {
+    /// The wall-clock time elapsed since the progress got created.
+    pub(crate) elapsed: Duration,
+    /// The wall-clock time elapsed since the progress got created,
+    /// excluding the time its task spent paused.
+    pub(crate) active_elapsed: Duration,
+    /// The (active) wall-clock time elapsed since the most recent update
+    /// of the progress' task, or of any of its subreports' tasks.
+    pub(crate) since_last_update: Duration,
+    /// The estimated recent completion rate of the progress' own task.
+    pub(crate) units_per_second: Option<f64>,
+}
+
+/// The structural changes between two reports, as returned by [`Report::diff()`].
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReportDiff {
+    /// The identifiers of reports that are present in the current,
+    /// but not in the previous report, in depth-first pre-order.
+    pub added: Vec<ProgressId>,
+    /// The identifiers of reports that are present in the previous,
+    /// but not in the current report, ordered by identifier.
+    pub removed: Vec<ProgressId>,
+    /// The identifiers of reports that are present in both reports,
+    /// but have changed in between, in depth-first pre-order.
+    pub changed: Vec<ProgressId>,
+}
+
+impl ReportDiff {
+    /// Returns `true` if no reports got added, removed, or changed, otherwise `false`.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl Default for Report {
+    fn default() -> Self {
+        Self {
+            progress_id: ProgressId::default(),
+            label: None,
+            completed: 0,
+            total: 0,
+            weight_completed: None,
+            weight_total: None,
+            fraction: 0.0,
+            is_indeterminate: false,
+            state: State::default(),
+            failures: 0,
+            highest_priority_seen: None,
+            subreports: vec![],
+            collapsed: false,
+            weight: 1.0,
+            unit_scale: 1.0,
+            unit_suffix: None,
+            descendant_count: 0,
+            last_change: Generation::default(),
+            timings: Timings::default(),
+        }
+    }
+}
+
+impl Clone for Report {
+    fn clone(&self) -> Self {
+        /// A partially cloned report, still awaiting the clones of its `subreports`.
+        struct Frame<'a> {
+            clone: Report,
+            subreports: std::slice::Iter<'a, Report>,
+        }
+
+        // Clone the subreports iteratively (rather than recursively),
+        // to support reports of arbitrary depth without overflowing the stack:
+        let mut stack = vec![Frame {
+            clone: self.clone_shallow(),
+            subreports: self.subreports.iter(),
+        }];
+
+        loop {
+            let frame = stack.last_mut().expect("non-empty stack");
+
+            if let Some(report) = frame.subreports.next() {
+                stack.push(Frame {
+                    clone: report.clone_shallow(),
+                    subreports: report.subreports.iter(),
+                });
+
+                continue;
+            }
+
+            let frame = stack.pop().expect("non-empty stack");
+
+            match stack.last_mut() {
+                Some(parent) => parent.clone.subreports.push(frame.clone),
+                None => return frame.clone,
+            }
+        }
+    }
+}
+
+impl PartialEq for Report {
+    fn eq(&self, other: &Self) -> bool {
+        // Compare the subreports iteratively (rather than recursively),
+        // to support reports of arbitrary depth without overflowing the stack:
+        let mut stack = vec![(self, other)];
+
+        while let Some((lhs, rhs)) = stack.pop() {
+            if !lhs.eq_shallow(rhs) || lhs.subreports.len() != rhs.subreports.len() {
+                return false;
+            }
+
+            stack.extend(lhs.subreports.iter().zip(&rhs.subreports));
+        }
+
+        true
+    }
+}
+
+impl Drop for Report {
+    fn drop(&mut self) {
+        // Tear down the subreports iteratively (rather than recursively, via each subreport's drop),
+        // to support reports of arbitrary depth without overflowing the stack:
+        let mut orphans = std::mem::take(&mut self.subreports);
+
+        while let Some(mut report) = orphans.pop() {
+            orphans.append(&mut report.subreports);
+        }
+    }
 }
 
 impl Report {
     pub(crate) fn new(
         progress_id: ProgressId,
-        label: Option<Cow<'static, str>>,
-        completed: usize,
-        total: usize,
-        state: State,
+        task: &Task,
+        timings: Timings,
+        highest_priority_seen: Option<PriorityLevel>,
+        aggregate: Aggregate,
         subreports: Vec<Report>,
         last_change: Generation,
     ) -> Self {
-        let completed = Self::completed(completed, total);
-        let total = Self::total(completed, total);
-        let fraction = Self::fraction(completed, total);
-        let is_indeterminate = Self::is_indeterminate(completed, total);
+        let (completed, total) = aggregate.clamped_discrete();
+        let weight_completed = aggregate.weighted.map(|(completed, _)| completed);
+        let weight_total = aggregate.weighted.map(|(_, total)| total);
+        let fraction = aggregate.fraction;
+        let is_indeterminate = aggregate.is_indeterminate();
+        let descendant_count = Self::descendant_count_of(&subreports);
+
+        let since_last_update = subreports
+            .iter()
+            .map(|subreport| subreport.timings.since_last_update)
+            .fold(timings.since_last_update, Duration::min);
+        let timings = Timings {
+            since_last_update,
+            ..timings
+        };
 
         Self {
             progress_id,
-            label,
+            label: task.label.clone(),
             completed,
             total,
+            weight_completed,
+            weight_total,
             fraction,
             is_indeterminate,
-            state,
+            state: task.state,
+            failures: task.failures,
+            highest_priority_seen,
             subreports,
+            collapsed: false,
+            weight: task.weight,
+            unit_scale: task.unit_scale,
+            unit_suffix: task.unit_suffix.clone(),
+            descendant_count,
             last_change,
+            timings,
+        }
+    }
+
+    pub(crate) fn new_collapsed(
+        progress_id: ProgressId,
+        task: &Task,
+        timings: Timings,
+        highest_priority_seen: Option<PriorityLevel>,
+        aggregate: Aggregate,
+        last_change: Generation,
+    ) -> Self {
+        let mut report = Self::new(
+            progress_id,
+            task,
+            timings,
+            highest_priority_seen,
+            aggregate,
+            vec![],
+            last_change,
+        );
+
+        report.collapsed = true;
+
+        report
+    }
+
+    /// Returns `true` if the report is determinate and all of its units are completed,
+    /// otherwise `false`.
+    pub fn is_complete(&self) -> bool {
+        !self.is_indeterminate && self.total > 0 && self.completed >= self.total
+    }
+
+    /// Returns the number of accumulative units yet to be completed
+    /// (i.e. `total - completed`, saturating at `0`).
+    ///
+    /// Returns `0` for indeterminate reports.
+    pub fn remaining(&self) -> usize {
+        self.total.saturating_sub(self.completed)
+    }
+
+    /// Returns the report's `fraction` as a percentage, rounded to the nearest integer
+    /// within `0..=100`, or `None` if the report is indeterminate.
+    pub fn percent(&self) -> Option<u8> {
+        if self.is_indeterminate {
+            return None;
+        }
+
+        Some(self.fraction_percent())
+    }
+
+    /// Returns the report's `fraction`, or `None` if the report has no total units.
+    ///
+    /// Unlike the eager `fraction` field, which reports `1.0` for completed units
+    /// without any total units (e.g. if `completed` gets set before `total`),
+    /// this treats any report without a total as indeterminate
+    /// (e.g. for showing a spinner, rather than a full bar).
+    pub fn fraction_strict(&self) -> Option<f64> {
+        let has_total = match self.weight_completed.zip(self.weight_total) {
+            Some((_, weight_total)) => weight_total > 0.0,
+            None => self.total > 0,
+        };
+
+        has_total.then_some(self.fraction)
+    }
+
+    /// Returns the highest priority level among the messages emitted by the progresses
+    /// of `self` and all of its subreports, recursively, or `None` if none emitted any.
+    ///
+    /// Only takes the reported subreports into account, i.e. not the ones omitted
+    /// from collapsed or pruned reports.
+    pub fn max_priority(&self) -> Option<PriorityLevel> {
+        self.iter()
+            .filter_map(|report| report.highest_priority_seen)
+            .max()
+    }
+
+    /// Returns the number of reports per (own) task state among `self`
+    /// and all of its subreports, recursively.
+    pub fn state_counts(&self) -> StateCounts {
+        let mut counts = StateCounts::default();
+
+        for report in self.iter() {
+            counts.count(report.state);
+        }
+
+        counts
+    }
+
+    /// Returns the number of direct children (i.e. `self.subreports.len()`).
+    pub fn direct_child_count(&self) -> usize {
+        self.subreports.len()
+    }
+
+    /// Returns the total number of descendants (excluding `self`),
+    /// i.e. the number of reports nested within `self.subreports`, recursively.
+    ///
+    /// See [`Report::descendant_count`](#structfield.descendant_count) for more info.
+    pub fn descendant_count(&self) -> usize {
+        self.descendant_count
+    }
+
+    /// Returns an iterator over `self` and all of its subreports, recursively.
+    ///
+    /// The reports get visited in depth-first pre-order,
+    /// i.e. each report gets visited before its subreports,
+    /// which in turn get visited in the order of `self.subreports`.
+    pub fn iter(&self) -> impl Iterator<Item = &Report> + '_ {
+        let mut stack = vec![self];
+
+        std::iter::from_fn(move || {
+            let report = stack.pop()?;
+            stack.extend(report.subreports.iter().rev());
+            Some(report)
+        })
+    }
+
+    /// Consumes the `Report` and returns `self` and all of its subreports, recursively,
+    /// with each returned report's `subreports` removed.
+    ///
+    /// The reports are in the same order as the ones of [`Report::iter()`].
+    pub fn flatten(self) -> Vec<Report> {
+        let mut reports = Vec::with_capacity(1 + self.descendant_count);
+        let mut stack = vec![self];
+
+        while let Some(mut report) = stack.pop() {
+            let subreports = std::mem::take(&mut report.subreports);
+            report.descendant_count = 0;
+
+            reports.push(report);
+            stack.extend(subreports.into_iter().rev());
         }
+
+        reports
     }
 
-    /// Returns the last change's generation.
+    /// Returns the report with the given `progress_id` among `self`
+    /// and all of its subreports, recursively, or `None` if there is none.
+    pub fn find(&self, progress_id: ProgressId) -> Option<&Report> {
+        self.iter().find(|report| report.progress_id == progress_id)
+    }
+
+    /// Returns the mutable report with the given `progress_id` among `self`
+    /// and all of its subreports, recursively, or `None` if there is none.
+    pub fn find_mut(&mut self, progress_id: ProgressId) -> Option<&mut Report> {
+        let mut stack = vec![self];
+
+        while let Some(report) = stack.pop() {
+            if report.progress_id == progress_id {
+                return Some(report);
+            }
+
+            stack.extend(report.subreports.iter_mut().rev());
+        }
+
+        None
+    }
+
+    /// Returns the non-empty labels of the reports on the way from `self` down to
+    /// the report with the given `progress_id` (both inclusive), joined by `separator`
+    /// (e.g. `"Parent > Child"`), or `None` if no such report exists.
+    ///
+    /// Reports without a label get skipped.
+    pub fn label_path(&self, progress_id: ProgressId, separator: &str) -> Option<String> {
+        self.label_path_with_prefix(progress_id, separator, "")
+    }
+
+    fn label_path_with_prefix(
+        &self,
+        progress_id: ProgressId,
+        separator: &str,
+        prefix: &str,
+    ) -> Option<String> {
+        let path = match self.label.as_deref() {
+            Some(label) if !label.is_empty() && !prefix.is_empty() => {
+                format!("{prefix}{separator}{label}")
+            }
+            Some(label) if !label.is_empty() => label.to_owned(),
+            _ => prefix.to_owned(),
+        };
+
+        if self.progress_id == progress_id {
+            return Some(path);
+        }
+
+        self.subreports
+            .iter()
+            .find_map(|report| report.label_path_with_prefix(progress_id, separator, &path))
+    }
+
+    /// Returns the last change's generation, i.e. the generation at which
+    /// the associated task, or any of its sub-tasks, were most recently changed.
+    ///
+    /// For incremental UI updates keep track of the most recently seen
+    /// generation and only redraw those subreports that changed since:
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use sitrep::{Generation, NopObserver, Progress, Reporter, Task};
+    /// let (progress, reporter) = Progress::new(Task::default(), Arc::new(NopObserver));
+    /// let child = Progress::new_with_parent(Task::default(), &progress);
+    /// let reporter = reporter.upgrade().unwrap();
+    ///
+    /// let mut last_seen = Generation::MIN;
+    ///
+    /// let report = reporter.report();
+    /// // ... draw the whole report ...
+    /// last_seen = report.last_change();
+    ///
+    /// child.set_completed(1);
+    ///
+    /// let report = reporter.report();
+    ///
+    /// if report.last_change() > last_seen {
+    ///     // Subreports whose generation is older than `last_seen` get pruned:
+    ///     let pruned = report.to_pruned(last_seen).unwrap();
+    ///     // ... only redraw the pruned report's subreports ...
+    ///     # assert_eq!(pruned.subreports.len(), 1);
+    ///     last_seen = report.last_change();
+    /// }
+    /// # let _ = last_seen;
+    /// ```
+    ///
+    /// See [`Reporter::partial_report()`](crate::Reporter::partial_report)
+    /// for obtaining such a pruned report without generating a full one first.
     pub fn last_change(&self) -> Generation {
         self.last_change
     }
 
+    /// Returns the wall-clock time elapsed since the associated progress got created
+    /// (as measured by its `Clock`), up until the report got generated.
+    ///
+    /// A parent reports its own elapsed time, not the sum of its subreports'.
+    pub fn elapsed(&self) -> Duration {
+        self.timings.elapsed
+    }
+
+    /// Returns the wall-clock time elapsed since the associated progress got created,
+    /// excluding the time its own task spent paused (i.e. `elapsed()` minus paused time).
+    ///
+    /// Only the progress' own pauses are accounted for, not those of its sub-progresses.
+    pub fn active_elapsed(&self) -> Duration {
+        self.timings.active_elapsed
+    }
+
+    /// Returns the estimated time remaining, extrapolated from the
+    /// accumulative `fraction` and the (active) wall-clock time it took
+    /// to get there, or `None` if the report is indeterminate,
+    /// or no progress has been made yet.
+    ///
+    /// As the `fraction` reflects the state as of the most recent update,
+    /// the extrapolation is based on the (active) wall-clock time elapsed
+    /// from the associated progress' creation up until its most recent update
+    /// (or the most recent update of any of its subreports), with the time
+    /// elapsed since then getting deducted from the estimate.
+    /// The estimate thus keeps counting down in between updates.
+    ///
+    /// As the estimate is based on the accumulative `fraction`,
+    /// a parent's estimate reflects the progress of its entire tree.
+    pub fn eta(&self) -> Option<Duration> {
+        if self.is_indeterminate || self.fraction <= 0.0 {
+            return None;
+        }
+
+        if self.fraction >= 1.0 {
+            return Some(Duration::ZERO);
+        }
+
+        let Timings {
+            active_elapsed,
+            since_last_update,
+            ..
+        } = self.timings;
+
+        let elapsed_until_update = active_elapsed.saturating_sub(since_last_update);
+        let remaining = (1.0 - self.fraction) / self.fraction;
+
+        Some(
+            elapsed_until_update
+                .mul_f64(remaining)
+                .saturating_sub(since_last_update),
+        )
+    }
+
+    /// Returns the recent completion rate of the associated progress' own task
+    /// (i.e. excluding its sub-progresses), in units per second,
+    /// or `None` if not enough samples have been taken yet.
+    ///
+    /// The rate is estimated as an exponentially-weighted moving average of
+    /// the rates observed between changes of the task's completed unit count,
+    /// so that brief stalls don't wreck the estimate.
+    /// See [`ProgressBuilder::rate_smoothing()`](crate::ProgressBuilder::rate_smoothing)
+    /// for tuning its responsiveness.
+    pub fn units_per_second(&self) -> Option<f64> {
+        self.timings.units_per_second
+    }
+
+    /// Returns the aggregate state of the report and its subreports (recursively).
+    ///
+    /// The aggregate state is determined by the following precedence rule:
+    ///
+    /// 1. `Canceled`, if any of the states is `Canceled`, otherwise
+    /// 2. `Paused`, if any of the states is `Paused`, otherwise
+    /// 3. `Running`, if any of the states is `Running`, otherwise
+    /// 4. `Finished` (i.e. if all of the states are `Finished`).
+    pub fn aggregate_state(&self) -> State {
+        fn precedence(state: State) -> u8 {
+            match state {
+                State::Finished => 0,
+                State::Running => 1,
+                State::Paused => 2,
+                State::Canceled => 3,
+            }
+        }
+
+        self.subreports
+            .iter()
+            .map(Report::aggregate_state)
+            .fold(self.state, |aggregate, state| {
+                if precedence(state) > precedence(aggregate) {
+                    state
+                } else {
+                    aggregate
+                }
+            })
+    }
+
+    /// Computes the changes between `previous` and `self`,
+    /// with reports being matched by their `progress_id`, recursively.
+    ///
+    /// A report is considered changed if its `last_change` generation
+    /// is newer than the previous one's, or if any of its own values differ
+    /// (ignoring its subreports, as well as its time measurements).
+    pub fn diff(&self, previous: &Report) -> ReportDiff {
+        let mut previous_reports = HashMap::new();
+        previous.collect_by_id(&mut previous_reports);
+
+        let mut diff = ReportDiff::default();
+
+        for report in self.iter() {
+            match previous_reports.remove(&report.progress_id) {
+                None => diff.added.push(report.progress_id),
+                Some(previous) if report.differs_from(previous) => {
+                    diff.changed.push(report.progress_id)
+                }
+                Some(_) => {}
+            }
+        }
+
+        diff.removed = previous_reports.into_keys().collect();
+        diff.removed.sort();
+
+        diff
+    }
+
+    /// Describes the changes between `previous` and `self` in prose,
+    /// one line per change (e.g. `"child 'compile' advanced 40%→60%"`),
+    /// with reports being matched by their `progress_id`.
+    ///
+    /// Reports get referred to by their label, or by their identifier if they have none.
+    ///
+    /// Changes of `self` itself get listed first, followed by those of its
+    /// (added, or changed) descendants in depth-first order,
+    /// followed by those that got removed.
+    pub fn describe_changes(&self, previous: &Report) -> Vec<String> {
+        let mut previous_reports = HashMap::new();
+        previous.collect_by_id(&mut previous_reports);
+
+        let mut descriptions = vec![];
+        self.describe_changes_impl(&mut previous_reports, &mut descriptions, true);
+
+        let mut removed: Vec<_> = previous_reports.into_values().collect();
+        removed.sort_by_key(|report| report.progress_id);
+
+        for report in removed {
+            descriptions.push(format!("{} removed", report.describe_name(false)));
+        }
+
+        descriptions
+    }
+
+    fn describe_changes_impl(
+        &self,
+        previous_reports: &mut HashMap<ProgressId, &Report>,
+        descriptions: &mut Vec<String>,
+        is_root: bool,
+    ) {
+        let name = self.describe_name(is_root);
+
+        match previous_reports.remove(&self.progress_id) {
+            None => descriptions.push(format!("{name} added")),
+            Some(previous) => {
+                let (old_percent, new_percent) =
+                    (previous.fraction_percent(), self.fraction_percent());
+
+                if new_percent != old_percent {
+                    let verb = if new_percent > old_percent {
+                        "advanced"
+                    } else {
+                        "regressed"
+                    };
+                    descriptions.push(format!("{name} {verb} {old_percent}%→{new_percent}%"));
+                }
+
+                if self.state != previous.state {
+                    let verb = match (previous.state, self.state) {
+                        (State::Paused, State::Running) => "resumed",
+                        (_, State::Running) => "restarted",
+                        (_, State::Paused) => "paused",
+                        (_, State::Finished) => "finished",
+                        (_, State::Canceled) => "canceled",
+                    };
+                    descriptions.push(format!("{name} {verb}"));
+                }
+            }
+        }
+
+        for subreport in &self.subreports {
+            subreport.describe_changes_impl(previous_reports, descriptions, false);
+        }
+    }
+
+    fn collect_by_id<'a>(&'a self, reports: &mut HashMap<ProgressId, &'a Report>) {
+        reports.insert(self.progress_id, self);
+
+        for subreport in &self.subreports {
+            subreport.collect_by_id(reports);
+        }
+    }
+
+    fn differs_from(&self, previous: &Report) -> bool {
+        self.last_change.is_newer_than(previous.last_change)
+            || self.label != previous.label
+            || self.completed != previous.completed
+            || self.total != previous.total
+            || self.weight_completed != previous.weight_completed
+            || self.weight_total != previous.weight_total
+            || self.fraction != previous.fraction
+            || self.is_indeterminate != previous.is_indeterminate
+            || self.state != previous.state
+            || self.failures != previous.failures
+            || self.highest_priority_seen != previous.highest_priority_seen
+            || self.weight != previous.weight
+            || self.unit_scale != previous.unit_scale
+            || self.unit_suffix != previous.unit_suffix
+    }
+
+    fn describe_name(&self, is_root: bool) -> String {
+        let kind = if is_root { "progress" } else { "child" };
+
+        match &self.label {
+            Some(label) => format!("{kind} '{label}'"),
+            None => format!("{kind} #{}", self.progress_id.as_raw()),
+        }
+    }
+
+    fn fraction_percent(&self) -> u8 {
+        (self.fraction.clamp(0.0, 1.0) * 100.0).round() as u8
+    }
+
+    /// Formats the report's accumulative unit counts for display,
+    /// dividing them by `self.unit_scale` and appending `self.unit_suffix`
+    /// (e.g. `"2.0 KiB / 4.0 KiB"` for `2048 / 4096` with a scale of `1024.0`).
+    ///
+    /// Unscaled unit counts (i.e. with a `self.unit_scale` of `1.0`)
+    /// get formatted as integers (e.g. `"2 / 4"`).
+    pub fn format_progress(&self) -> String {
+        let format_units = |units: usize| {
+            let value = if self.unit_scale == 1.0 {
+                units.to_string()
+            } else {
+                format!("{:.1}", units as f64 / self.unit_scale)
+            };
+
+            match &self.unit_suffix {
+                Some(suffix) => format!("{value} {suffix}"),
+                None => value,
+            }
+        };
+
+        format!(
+            "{} / {}",
+            format_units(self.completed),
+            format_units(self.total)
+        )
+    }
+
+    /// Renders a progress bar of `width` characters from `self.fraction`,
+    /// using Unicode block characters (e.g. `█████░░░░░`).
+    ///
+    /// Indeterminate reports get rendered as the first frame of
+    /// the animation provided by `bar_frame()`.
+    pub fn bar(&self, width: usize) -> String {
+        self.bar_frame(width, 0)
+    }
+
+    /// Renders a progress bar of `width` characters from `self.fraction`,
+    /// using Unicode block characters (e.g. `█████░░░░░`).
+    ///
+    /// Indeterminate reports get rendered as a short segment of shaded blocks
+    /// (e.g. `░░▓▓▓░░░░░`) moving across the bar with increasing `frame`,
+    /// wrapping around at the end.
+    pub fn bar_frame(&self, width: usize, frame: usize) -> String {
+        const FILLED: char = '█';
+        const EMPTY: char = '░';
+        const INDETERMINATE: char = '▓';
+        const INDETERMINATE_LEN: usize = 3;
+
+        if self.is_indeterminate {
+            if width == 0 {
+                return String::new();
+            }
+
+            let offset = frame % width;
+            let segment_len = INDETERMINATE_LEN.min(width);
+
+            return (0..width)
+                .map(|index| {
+                    let distance = (index + width - offset) % width;
+                    if distance < segment_len {
+                        INDETERMINATE
+                    } else {
+                        EMPTY
+                    }
+                })
+                .collect();
+        }
+
+        let fraction = self.fraction.clamp(0.0, 1.0);
+        let filled = ((fraction * width as f64) as usize).min(width);
+
+        std::iter::repeat(FILLED)
+            .take(filled)
+            .chain(std::iter::repeat(EMPTY).take(width - filled))
+            .collect()
+    }
+
     /// Returns a pruned version with all subreports older than
     /// `min_last_change` removed, or `None` if `self` itself is older.
     pub fn to_pruned(&self, min_last_change: Generation) -> Option<Self> {
@@ -87,47 +826,446 @@ impl Report {
         self.subreports
             .retain_mut(|report| report.prune(min_last_change));
 
+        self.descendant_count = Self::descendant_count_of(&self.subreports);
+
         self.last_change >= min_last_change
     }
 
-    fn completed(completed: usize, total: usize) -> usize {
-        completed.min(total)
-    }
+    /// Returns a clone of `self`, without any of its subreports.
+    fn clone_shallow(&self) -> Self {
+        let Self {
+            progress_id,
+            label,
+            completed,
+            total,
+            weight_completed,
+            weight_total,
+            fraction,
+            is_indeterminate,
+            state,
+            failures,
+            highest_priority_seen,
+            subreports,
+            collapsed,
+            weight,
+            unit_scale,
+            unit_suffix,
+            descendant_count,
+            last_change,
+            timings,
+        } = self;
 
-    fn total(completed: usize, total: usize) -> usize {
-        completed.max(total)
+        Self {
+            progress_id: *progress_id,
+            label: label.clone(),
+            completed: *completed,
+            total: *total,
+            weight_completed: *weight_completed,
+            weight_total: *weight_total,
+            fraction: *fraction,
+            is_indeterminate: *is_indeterminate,
+            state: *state,
+            failures: *failures,
+            highest_priority_seen: *highest_priority_seen,
+            subreports: Vec::with_capacity(subreports.len()),
+            collapsed: *collapsed,
+            weight: *weight,
+            unit_scale: *unit_scale,
+            unit_suffix: unit_suffix.clone(),
+            descendant_count: *descendant_count,
+            last_change: *last_change,
+            timings: *timings,
+        }
     }
 
-    fn fraction(completed: usize, total: usize) -> f64 {
-        match (completed, total) {
-            (0, 0) => 0.0,
-            (_, 0) => 1.0,
-            (completed, total) => 1.0 * (completed as f64) / (total as f64),
-        }
+    /// Returns `true` if `self` and `other` are equal, ignoring their subreports.
+    fn eq_shallow(&self, other: &Self) -> bool {
+        let Self {
+            progress_id,
+            label,
+            completed,
+            total,
+            weight_completed,
+            weight_total,
+            fraction,
+            is_indeterminate,
+            state,
+            failures,
+            highest_priority_seen,
+            subreports: _,
+            collapsed,
+            weight,
+            unit_scale,
+            unit_suffix,
+            descendant_count,
+            last_change,
+            timings,
+        } = self;
+
+        *progress_id == other.progress_id
+            && *label == other.label
+            && *completed == other.completed
+            && *total == other.total
+            && *weight_completed == other.weight_completed
+            && *weight_total == other.weight_total
+            && *fraction == other.fraction
+            && *is_indeterminate == other.is_indeterminate
+            && *state == other.state
+            && *failures == other.failures
+            && *highest_priority_seen == other.highest_priority_seen
+            && *collapsed == other.collapsed
+            && *weight == other.weight
+            && *unit_scale == other.unit_scale
+            && *unit_suffix == other.unit_suffix
+            && *descendant_count == other.descendant_count
+            && *last_change == other.last_change
+            && *timings == other.timings
     }
 
-    fn is_indeterminate(completed: usize, total: usize) -> bool {
-        (completed == 0) && (total == 0)
+    fn descendant_count_of(subreports: &[Report]) -> usize {
+        subreports
+            .iter()
+            .map(|report| 1 + report.descendant_count)
+            .sum()
     }
 
     pub(crate) fn discrete(&self) -> (usize, usize) {
         (self.completed, self.total)
     }
+
+    pub(crate) fn aggregate(&self) -> Aggregate {
+        Aggregate {
+            completed: self.completed,
+            total: self.total,
+            weighted: self.weight_completed.zip(self.weight_total),
+            fraction: self.fraction,
+        }
+    }
+}
+
+/// Aggregates independent `reports` into a combined `(completed, total, fraction)` tuple.
+///
+/// Indeterminate reports contribute nothing to the aggregate.
+pub fn aggregate_reports(reports: &[Report]) -> (usize, usize, f64) {
+    let (completed, total) = reports
+        .iter()
+        .filter(|report| !report.is_indeterminate)
+        .map(|report| report.discrete())
+        .fold((0, 0), |sum: (usize, usize), item| {
+            (sum.0.saturating_add(item.0), sum.1.saturating_add(item.1))
+        });
+
+    let fraction = Aggregate::discrete_fraction(completed, total);
+
+    (completed, total, fraction)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Creates a report from `base` (defaulting to `Report::default()`)
+    /// with the given fields overridden, since `Report` implementing `Drop`
+    /// rules out struct update syntax (i.e. `..base`).
+    macro_rules! report {
+        (@value $field:ident: $value:expr) => {
+            $value
+        };
+        (@value $field:ident) => {
+            $field
+        };
+        ($($field:ident $(: $value:expr)?),* $(,)?) => {
+            report!(Report::default(); $($field $(: $value)?),*)
+        };
+        ($base:expr; $($field:ident $(: $value:expr)?),* $(,)?) => {{
+            let mut report: Report = $base;
+            $(report.$field = report!(@value $field $(: $value)?);)*
+            report
+        }};
+    }
+
+    #[cfg(feature = "serde")]
+    mod serialization {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            let report = report! {
+                progress_id: ProgressId::new_unique(),
+                label: Some("parent".into()),
+                completed: 3,
+                total: 4,
+                subreports: vec![report! {
+                    progress_id: ProgressId::new_unique(),
+                    label: Some("child".into()),
+                    completed: 1,
+                    total: 2,
+                    state: State::Paused,
+                    last_change: Generation(2),
+                }],
+                descendant_count: 1,
+                last_change: Generation(3),
+            };
+
+            let json = serde_json::to_string(&report).unwrap();
+            let deserialized: Report = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(deserialized.progress_id, report.progress_id);
+            assert_eq!(deserialized.label, report.label);
+            assert_eq!(deserialized.completed, report.completed);
+            assert_eq!(deserialized.total, report.total);
+            assert_eq!(deserialized.subreports, report.subreports);
+            assert_eq!(deserialized, report);
+        }
+    }
+
+    mod eta {
+        use super::*;
+
+        fn report(fraction: f64, is_indeterminate: bool, elapsed: Duration) -> Report {
+            report! {
+                fraction,
+                is_indeterminate,
+                timings: Timings {
+                    elapsed,
+                    active_elapsed: elapsed,
+                    ..Default::default()
+                },
+            }
+        }
+
+        #[test]
+        fn extrapolated() {
+            let report = report(0.25, false, Duration::from_secs(10));
+
+            assert_eq!(report.eta(), Some(Duration::from_secs(30)));
+        }
+
+        #[test]
+        fn since_last_update() {
+            let mut report = report(0.25, false, Duration::from_secs(14));
+            report.timings.since_last_update = Duration::from_secs(4);
+
+            // A quarter of the work took 10s, 4s of the remaining 30s have passed since:
+            assert_eq!(report.eta(), Some(Duration::from_secs(26)));
+
+            report.timings.since_last_update = Duration::from_secs(12);
+
+            assert_eq!(report.eta(), Some(Duration::ZERO));
+        }
+
+        #[test]
+        fn none() {
+            assert_eq!(report(0.0, false, Duration::from_secs(10)).eta(), None);
+            assert_eq!(report(0.0, true, Duration::from_secs(10)).eta(), None);
+        }
+    }
+
+    mod aggregate_state {
+        use super::*;
+
+        fn report(state: State, subreports: Vec<Report>) -> Report {
+            report! {
+                state,
+                subreports,
+            }
+        }
+
+        #[test]
+        fn leaf() {
+            assert_eq!(
+                report(State::Paused, vec![]).aggregate_state(),
+                State::Paused
+            );
+        }
+
+        #[test]
+        fn canceled_wins() {
+            let report = report(
+                State::Running,
+                vec![
+                    report(State::Paused, vec![]),
+                    report(State::Finished, vec![report(State::Canceled, vec![])]),
+                ],
+            );
+
+            assert_eq!(report.aggregate_state(), State::Canceled);
+        }
+
+        #[test]
+        fn paused_over_running() {
+            let report = report(
+                State::Running,
+                vec![report(State::Running, vec![report(State::Paused, vec![])])],
+            );
+
+            assert_eq!(report.aggregate_state(), State::Paused);
+        }
+
+        #[test]
+        fn running_over_finished() {
+            let report = report(
+                State::Finished,
+                vec![
+                    report(State::Finished, vec![]),
+                    report(State::Running, vec![]),
+                ],
+            );
+
+            assert_eq!(report.aggregate_state(), State::Running);
+        }
+
+        #[test]
+        fn all_finished() {
+            let report = report(State::Finished, vec![report(State::Finished, vec![])]);
+
+            assert_eq!(report.aggregate_state(), State::Finished);
+        }
+    }
+
+    mod describe_changes {
+        use super::*;
+
+        fn report(label: &'static str, fraction: f64, state: State) -> Report {
+            report! {
+                progress_id: ProgressId::new_unique(),
+                label: Some(label.into()),
+                fraction,
+                state,
+            }
+        }
+
+        #[test]
+        fn advance_addition_cancellation() {
+            let compile = report("compile", 0.4, State::Running);
+            let test = report("test", 0.0, State::Running);
+            let link = report("link", 0.0, State::Running);
+
+            let previous = report! {
+                report("build", 0.0, State::Running);
+                subreports: vec![compile.clone(), test.clone()],
+            };
+
+            let current = report! {
+                report("build", 0.0, State::Running);
+                progress_id: previous.progress_id,
+                subreports: vec![
+                    report! {
+                        compile;
+                        fraction: 0.6,
+                    },
+                    link,
+                    report! {
+                        test;
+                        state: State::Canceled,
+                    },
+                ],
+            };
+
+            assert_eq!(
+                current.describe_changes(&previous),
+                vec![
+                    "child 'compile' advanced 40%→60%",
+                    "child 'link' added",
+                    "child 'test' canceled",
+                ]
+            );
+        }
+
+        #[test]
+        fn removal() {
+            let child = report("child", 0.0, State::Running);
+
+            let previous = report! {
+                report("root", 0.0, State::Running);
+                subreports: vec![child],
+            };
+
+            let current = report! {
+                previous.clone();
+                subreports: vec![],
+            };
+
+            assert_eq!(
+                current.describe_changes(&previous),
+                vec!["child 'child' removed"]
+            );
+            assert!(previous.describe_changes(&previous).is_empty());
+        }
+    }
+
+    mod format_progress {
+        use super::*;
+
+        #[test]
+        fn scaled() {
+            let report = report! {
+                completed: 2048,
+                total: 4096,
+                unit_scale: 1024.0,
+                unit_suffix: Some("KiB".into()),
+            };
+
+            assert_eq!(report.format_progress(), "2.0 KiB / 4.0 KiB");
+        }
+
+        #[test]
+        fn unscaled() {
+            let report = report! {
+                completed: 2,
+                total: 4,
+            };
+
+            assert_eq!(report.format_progress(), "2 / 4");
+        }
+    }
+
+    mod bar {
+        use super::*;
+
+        fn report(fraction: f64) -> Report {
+            report! {
+                fraction,
+            }
+        }
+
+        #[test]
+        fn half() {
+            assert_eq!(report(0.5).bar(10), "█████░░░░░");
+        }
+
+        #[test]
+        fn full() {
+            assert_eq!(report(1.0).bar(10), "██████████");
+        }
+
+        #[test]
+        fn empty() {
+            assert_eq!(report(0.0).bar(4), "░░░░");
+            assert_eq!(report(0.5).bar(0), "");
+        }
+
+        #[test]
+        fn indeterminate() {
+            let report = report! {
+                is_indeterminate: true,
+            };
+
+            assert_eq!(report.bar_frame(5, 0), "▓▓▓░░");
+            assert_eq!(report.bar_frame(5, 1), "░▓▓▓░");
+            assert_eq!(report.bar_frame(5, 3), "▓░░▓▓");
+            assert_eq!(report.bar_frame(5, 5), report.bar_frame(5, 0));
+        }
+    }
+
     mod to_pruned {
         use super::*;
 
         #[test]
         fn prunes_self() {
-            let report = Report {
+            let report = report! {
                 progress_id: ProgressId::new_unique(),
                 last_change: Generation(0),
-                ..Default::default()
             };
 
             assert_eq!(report.to_pruned(Generation(1)), None);
@@ -139,33 +1277,30 @@ mod tests {
             let child_id = ProgressId::new_unique();
             let grand_child_id = ProgressId::new_unique();
 
-            let report = Report {
+            let report = report! {
                 progress_id: parent_id,
                 subreports: vec![
-                    Report {
+                    report! {
                         progress_id: ProgressId::new_unique(),
                         last_change: Generation(1),
-                        ..Default::default()
                     },
-                    Report {
+                    report! {
                         progress_id: child_id,
-                        subreports: vec![Report {
+                        subreports: vec![report! {
                             progress_id: grand_child_id,
                             last_change: Generation(2),
-                            ..Default::default()
                         }],
                         last_change: Generation(2),
-                        ..Default::default()
                     },
                 ],
                 last_change: Generation(2),
-                ..Default::default()
             };
 
             let parent = report.to_pruned(Generation(2)).unwrap();
 
             assert_eq!(parent.progress_id, parent_id);
             assert_eq!(parent.subreports.len(), 1);
+            assert_eq!(parent.descendant_count(), 2);
 
             let child = &parent.subreports[0];
             assert_eq!(child.progress_id, child_id);
@@ -176,4 +1311,407 @@ mod tests {
             assert_eq!(grand_child.subreports.len(), 0);
         }
     }
+
+    mod completion {
+        use super::*;
+
+        #[test]
+        fn indeterminate() {
+            let report = report! {
+                is_indeterminate: true,
+            };
+
+            assert!(!report.is_complete());
+            assert_eq!(report.percent(), None);
+            assert_eq!(report.remaining(), 0);
+        }
+
+        #[test]
+        fn partial() {
+            let report = report! {
+                completed: 2,
+                total: 3,
+                fraction: 2.0 / 3.0,
+            };
+
+            assert!(!report.is_complete());
+            assert_eq!(report.percent(), Some(67));
+            assert_eq!(report.remaining(), 1);
+        }
+
+        #[test]
+        fn complete() {
+            let report = report! {
+                completed: 3,
+                total: 3,
+                fraction: 1.0,
+            };
+
+            assert!(report.is_complete());
+            assert_eq!(report.percent(), Some(100));
+            assert_eq!(report.remaining(), 0);
+        }
+    }
+
+    mod fraction_strict {
+        use super::*;
+
+        fn report(completed: usize, total: usize) -> Report {
+            report! {
+                completed,
+                total,
+                fraction: Aggregate::discrete_fraction(completed, total),
+            }
+        }
+
+        #[test]
+        fn completed_without_total() {
+            let report = report(5, 0);
+
+            assert_eq!(report.fraction, 1.0);
+            assert_eq!(report.fraction_strict(), None);
+        }
+
+        #[test]
+        fn empty() {
+            assert_eq!(report(0, 0).fraction_strict(), None);
+        }
+
+        #[test]
+        fn partial() {
+            assert_eq!(report(5, 10).fraction_strict(), Some(0.5));
+        }
+
+        #[test]
+        fn weighted() {
+            let report = report! {
+                report(5, 0);
+                weight_completed: Some(1.0),
+                weight_total: Some(4.0),
+                fraction: 0.25,
+            };
+
+            assert_eq!(report.fraction_strict(), Some(0.25));
+        }
+    }
+
+    mod iter {
+        use super::*;
+
+        fn report(label: &'static str, subreports: Vec<Report>) -> Report {
+            report! {
+                progress_id: ProgressId::new_unique(),
+                label: Some(label.into()),
+                descendant_count: Report::descendant_count_of(&subreports),
+                subreports,
+            }
+        }
+
+        fn nested() -> Report {
+            report(
+                "parent",
+                vec![
+                    report(
+                        "child 1",
+                        vec![
+                            report("grandchild 1.1", vec![]),
+                            report("grandchild 1.2", vec![]),
+                        ],
+                    ),
+                    report("child 2", vec![report("grandchild 2.1", vec![])]),
+                ],
+            )
+        }
+
+        const PRE_ORDER: [&str; 6] = [
+            "parent",
+            "child 1",
+            "grandchild 1.1",
+            "grandchild 1.2",
+            "child 2",
+            "grandchild 2.1",
+        ];
+
+        #[test]
+        fn pre_order() {
+            let report = nested();
+
+            let labels: Vec<_> = report
+                .iter()
+                .map(|report| report.label.as_deref().unwrap())
+                .collect();
+
+            assert_eq!(labels, PRE_ORDER);
+        }
+
+        #[test]
+        fn flatten() {
+            let reports = nested().flatten();
+
+            let labels: Vec<_> = reports
+                .iter()
+                .map(|report| report.label.as_deref().unwrap())
+                .collect();
+
+            assert_eq!(labels, PRE_ORDER);
+
+            for report in &reports {
+                assert_eq!(report.subreports, vec![]);
+                assert_eq!(report.descendant_count(), 0);
+            }
+        }
+    }
+
+    mod find {
+        use super::*;
+
+        fn nested(
+            parent_id: ProgressId,
+            child_id: ProgressId,
+            grandchild_id: ProgressId,
+        ) -> Report {
+            report! {
+                progress_id: parent_id,
+                subreports: vec![
+                    report! {
+                        progress_id: ProgressId::new_unique(),
+                    },
+                    report! {
+                        progress_id: child_id,
+                        subreports: vec![report! {
+                            progress_id: grandchild_id,
+                        }],
+                    },
+                ],
+            }
+        }
+
+        #[test]
+        fn root() {
+            let parent_id = ProgressId::new_unique();
+            let mut report = nested(
+                parent_id,
+                ProgressId::new_unique(),
+                ProgressId::new_unique(),
+            );
+
+            assert_eq!(report.find(parent_id).unwrap().progress_id, parent_id);
+            assert_eq!(report.find_mut(parent_id).unwrap().progress_id, parent_id);
+        }
+
+        #[test]
+        fn nested_match() {
+            let child_id = ProgressId::new_unique();
+            let grandchild_id = ProgressId::new_unique();
+            let mut report = nested(ProgressId::new_unique(), child_id, grandchild_id);
+
+            assert_eq!(report.find(child_id).unwrap().subreports.len(), 1);
+            assert_eq!(
+                report.find(grandchild_id).unwrap().progress_id,
+                grandchild_id
+            );
+
+            report.find_mut(grandchild_id).unwrap().completed = 42;
+
+            assert_eq!(report.subreports[1].subreports[0].completed, 42);
+        }
+
+        #[test]
+        fn missing() {
+            let mut report = nested(
+                ProgressId::new_unique(),
+                ProgressId::new_unique(),
+                ProgressId::new_unique(),
+            );
+
+            let missing_id = ProgressId::new_unique();
+
+            assert_eq!(report.find(missing_id), None);
+            assert_eq!(report.find_mut(missing_id), None);
+        }
+    }
+
+    #[test]
+    fn aggregate_reports() {
+        let reports = [
+            report! {
+                completed: 1,
+                total: 4,
+                fraction: 0.25,
+            },
+            report! {
+                completed: 2,
+                total: 2,
+                fraction: 1.0,
+            },
+            report! {
+                is_indeterminate: true,
+            },
+        ];
+
+        assert_eq!(super::aggregate_reports(&reports), (3, 6, 0.5));
+        assert_eq!(super::aggregate_reports(&reports[2..]), (0, 0, 0.0));
+    }
+
+    mod state_counts {
+        use super::*;
+
+        fn report(state: State, subreports: Vec<Report>) -> Report {
+            report! {
+                state,
+                subreports,
+            }
+        }
+
+        #[test]
+        fn leaf() {
+            let counts = report(State::Paused, vec![]).state_counts();
+
+            assert_eq!(
+                counts,
+                StateCounts {
+                    paused: 1,
+                    ..Default::default()
+                }
+            );
+            assert_eq!(counts.total(), 1);
+        }
+
+        #[test]
+        fn mixed() {
+            let report = report(
+                State::Running,
+                vec![
+                    report(
+                        State::Paused,
+                        vec![
+                            report(State::Finished, vec![]),
+                            report(State::Canceled, vec![]),
+                        ],
+                    ),
+                    report(
+                        State::Running,
+                        vec![
+                            report(State::Finished, vec![]),
+                            report(State::Finished, vec![]),
+                        ],
+                    ),
+                ],
+            );
+
+            let counts = report.state_counts();
+
+            assert_eq!(
+                counts,
+                StateCounts {
+                    running: 2,
+                    paused: 1,
+                    finished: 3,
+                    canceled: 1,
+                }
+            );
+            assert_eq!(counts.total(), 7);
+        }
+    }
+
+    mod diff {
+        use super::*;
+
+        fn report(progress_id: ProgressId, completed: usize, subreports: Vec<Report>) -> Report {
+            report! {
+                progress_id,
+                completed,
+                total: 10,
+                descendant_count: subreports.iter().map(|r| 1 + r.descendant_count).sum(),
+                subreports,
+            }
+        }
+
+        #[test]
+        fn unchanged() {
+            let (parent_id, child_id) = (ProgressId::new_unique(), ProgressId::new_unique());
+
+            let previous = report(parent_id, 0, vec![report(child_id, 0, vec![])]);
+            let mut current = previous.clone();
+            current.timings.elapsed = Duration::from_secs(1);
+
+            let diff = current.diff(&previous);
+
+            assert!(diff.is_empty());
+            assert_eq!(diff, ReportDiff::default());
+        }
+
+        #[test]
+        fn added() {
+            let (parent_id, child_id) = (ProgressId::new_unique(), ProgressId::new_unique());
+
+            let previous = report(parent_id, 0, vec![]);
+            let current = report(parent_id, 0, vec![report(child_id, 0, vec![])]);
+
+            let diff = current.diff(&previous);
+
+            assert_eq!(diff.added, vec![child_id]);
+            assert!(diff.removed.is_empty());
+            assert!(diff.changed.is_empty());
+        }
+
+        #[test]
+        fn removed() {
+            let (parent_id, child_id) = (ProgressId::new_unique(), ProgressId::new_unique());
+            let grandchild_id = ProgressId::new_unique();
+
+            let previous = report(
+                parent_id,
+                0,
+                vec![report(child_id, 0, vec![report(grandchild_id, 0, vec![])])],
+            );
+            let current = report(parent_id, 0, vec![]);
+
+            let diff = current.diff(&previous);
+
+            assert!(diff.added.is_empty());
+            assert_eq!(diff.removed, vec![child_id, grandchild_id]);
+            assert!(diff.changed.is_empty());
+        }
+
+        #[test]
+        fn changed() {
+            let (parent_id, child_id) = (ProgressId::new_unique(), ProgressId::new_unique());
+            let sibling_id = ProgressId::new_unique();
+
+            let previous = report(
+                parent_id,
+                1,
+                vec![report(child_id, 0, vec![]), report(sibling_id, 1, vec![])],
+            );
+            let current = report(
+                parent_id,
+                2,
+                vec![report(child_id, 1, vec![]), report(sibling_id, 1, vec![])],
+            );
+
+            let diff = current.diff(&previous);
+
+            assert!(diff.added.is_empty());
+            assert!(diff.removed.is_empty());
+            assert_eq!(diff.changed, vec![parent_id, child_id]);
+        }
+
+        #[test]
+        fn last_change() {
+            let progress_id = ProgressId::new_unique();
+
+            let previous = report! {
+                report(progress_id, 0, vec![]);
+                last_change: Generation(1),
+            };
+            let current = report! {
+                report(progress_id, 0, vec![]);
+                last_change: Generation(2),
+            };
+
+            assert_eq!(current.diff(&previous).changed, vec![progress_id]);
+            assert!(previous.diff(&current).changed.is_empty());
+        }
+    }
 }
@@ -0,0 +1,59 @@
+//! A report sampling iterator.
+
+use std::{
+    sync::Weak,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{Report, Reporter};
+
+/// An iterator yielding a progress' report at a fixed interval.
+///
+/// Each call to `next()` blocks until the next tick of the interval.
+/// The iterator terminates once the associated progress tree has been dropped.
+///
+/// Created by [`Reporter::sampled()`](Reporter::sampled).
+#[derive(Debug)]
+pub struct Sampled<R> {
+    reporter: Weak<R>,
+    interval: Duration,
+    next_tick: Instant,
+}
+
+impl<R> Sampled<R> {
+    pub(crate) fn new(reporter: Weak<R>, interval: Duration) -> Self {
+        let next_tick = Instant::now() + interval;
+
+        Self {
+            reporter,
+            interval,
+            next_tick,
+        }
+    }
+}
+
+impl<R> Iterator for Sampled<R>
+where
+    R: Reporter,
+{
+    type Item = Report;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let now = Instant::now();
+
+        if self.next_tick > now {
+            thread::sleep(self.next_tick - now);
+        }
+
+        // Skip any ticks we might have missed due to slow consumption:
+        let now = Instant::now();
+        while self.next_tick <= now {
+            self.next_tick += self.interval.max(Duration::from_nanos(1));
+        }
+
+        let reporter = self.reporter.upgrade()?;
+
+        Some(reporter.report())
+    }
+}
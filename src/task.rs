@@ -2,8 +2,11 @@
 
 use std::borrow::Cow;
 
+use crate::aggregate::Aggregate;
+
 /// A task's state.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum State {
     /// A running task.
@@ -18,7 +21,22 @@ pub enum State {
 }
 
 /// The task associated with a given progress object.
-#[derive(Clone, PartialEq, Default, Debug)]
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use sitrep::{Controller, NopObserver, Progress, Task};
+/// let task = Task::default()
+///     .label("Downloading")
+///     .total(100)
+///     .pausable(true)
+///     .cancelable(true);
+///
+/// let (progress, _reporter) = Progress::new(task, Arc::new(NopObserver));
+///
+/// assert!(progress.is_pausable());
+/// assert!(progress.is_cancelable());
+/// ```
+#[derive(Clone, PartialEq, Debug)]
 pub struct Task {
     /// The task's label.
     pub label: Option<Cow<'static, str>>,
@@ -28,10 +46,43 @@ pub struct Task {
     pub total: usize,
     /// The task's state.
     pub state: State,
+    /// The task's failed unit count.
+    ///
+    /// Failed units are still counted as completed.
+    pub failures: usize,
     /// Whether or not the task is cancelable.
     pub is_cancelable: bool,
     /// Whether or not the task is pausable.
     pub is_pausable: bool,
+    /// The task's completed weighted unit count, if measured in fractional units.
+    pub weight_completed: Option<f64>,
+    /// The task's total weighted unit count, if measured in fractional units.
+    pub weight_total: Option<f64>,
+    /// The task's relative weight within its parent's aggregate.
+    pub weight: f64,
+    /// The scale by which to divide the task's unit counts when displaying them.
+    pub unit_scale: f64,
+    /// The suffix to append to the task's unit counts when displaying them.
+    pub unit_suffix: Option<Cow<'static, str>>,
+}
+
+impl Default for Task {
+    fn default() -> Self {
+        Self {
+            label: None,
+            completed: 0,
+            total: 0,
+            state: State::default(),
+            failures: 0,
+            is_cancelable: false,
+            is_pausable: false,
+            weight_completed: None,
+            weight_total: None,
+            weight: 1.0,
+            unit_scale: 1.0,
+            unit_suffix: None,
+        }
+    }
 }
 
 impl Task {
@@ -61,31 +112,144 @@ impl Task {
         self
     }
 
-    /// Builder-style method for marking the task as being cancelable.
+    /// Builder-style method for setting the task's initial completed weighted unit count.
+    ///
+    /// The default completed weighted unit count is `None`.
+    ///
+    /// See [`Task::weight_total()`] for more info.
+    pub fn weight_completed(mut self, weight_completed: f64) -> Self {
+        self.weight_completed = Some(weight_completed);
+        self
+    }
+
+    /// Builder-style method for setting the task's initial total weighted unit count.
+    ///
+    /// The default total weighted unit count is `None`.
+    ///
+    /// # Weighted units
+    ///
+    /// Weighted units allow for measuring work in fractional units
+    /// (e.g. estimated seconds, or cost), rather than integer counts.
+    ///
+    /// A task with either of its weighted unit counts set to `Some(…)` contributes
+    /// its weighted units (with a missing count being treated as `0.0`) to
+    /// the report's weighted fraction, instead of its integer unit counts.
+    /// Tasks without weighted units contribute their integer unit counts instead.
+    pub fn weight_total(mut self, weight_total: f64) -> Self {
+        self.weight_total = Some(weight_total);
+        self
+    }
+
+    /// Builder-style method for setting the task's relative weight.
+    ///
+    /// The default weight is `1.0`.
+    ///
+    /// As long as all sub-tasks of a task have the default weight, the task's fraction
+    /// is proportional to their accumulated units of work. As soon as any of them
+    /// has a different weight, the task's fraction becomes the weighted mean of its
+    /// (determinate) sub-tasks' fractions (i.e. `Σ weight × fraction / Σ weight`),
+    /// with its own units of work (if any) having a weight of `1.0`.
+    /// This allows a sub-task to have a bigger (or smaller) influence
+    /// on its parent's fraction, regardless of its number of units.
+    ///
+    /// The weight does not affect the task's own fraction,
+    /// nor its parent's `completed` and `total` unit counts (discrete, or weighted).
+    pub fn weight(mut self, weight: f64) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Builder-style method for setting the task's display unit scale.
+    ///
+    /// The default unit scale is `1.0`.
+    ///
+    /// The unit scale only affects how unit counts get displayed
+    /// (e.g. a task counting bytes being displayed in KiB with a scale of `1024.0`),
+    /// not the unit counts themselves. See [`Report::format_progress()`](crate::Report::format_progress).
+    pub fn unit_scale(mut self, unit_scale: f64) -> Self {
+        self.unit_scale = unit_scale;
+        self
+    }
+
+    /// Builder-style method for setting the task's display unit suffix.
+    ///
+    /// The default unit suffix is `None`.
+    pub fn unit_suffix(mut self, unit_suffix: impl Into<Cow<'static, str>>) -> Self {
+        self.unit_suffix = Some(unit_suffix.into());
+        self
+    }
+
+    /// Builder-style method for setting the task's initial state.
+    ///
+    /// The default state is `State::Running`.
+    pub fn state(mut self, state: State) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Builder-style method for setting whether or not the task is cancelable.
     ///
     /// The default is `false` (i.e. non-cancelable).
-    pub fn cancelable(mut self) -> Self {
-        self.is_cancelable = true;
+    pub fn cancelable(mut self, is_cancelable: bool) -> Self {
+        self.is_cancelable = is_cancelable;
         self
     }
 
-    /// Builder-style method for marking the task as being pausable.
+    /// Builder-style method for setting whether or not the task is pausable.
     ///
     /// The default is `false` (i.e. non-pausable).
-    pub fn pausable(mut self) -> Self {
-        self.is_pausable = true;
+    pub fn pausable(mut self, is_pausable: bool) -> Self {
+        self.is_pausable = is_pausable;
         self
     }
 
+    /// Returns the number of units yet to be completed
+    /// (i.e. `total - completed`, saturating at `0`).
+    pub fn remaining(&self) -> usize {
+        self.total.saturating_sub(self.completed)
+    }
+
     pub(crate) fn effective_completed(&self) -> usize {
+        if self.total == 0 {
+            // Completed units without any total units (yet) are treated as complete:
+            return self.completed;
+        }
+
         self.completed.min(self.total)
     }
 
     pub(crate) fn effective_total(&self) -> usize {
+        if self.total == 0 {
+            return 0;
+        }
+
         self.completed.max(self.total)
     }
 
     pub(crate) fn effective_discrete(&self) -> (usize, usize) {
         (self.effective_completed(), self.effective_total())
     }
+
+    pub(crate) fn effective_weighted(&self) -> Option<(f64, f64)> {
+        if self.weight_completed.is_none() && self.weight_total.is_none() {
+            return None;
+        }
+
+        let completed = self.weight_completed.unwrap_or(0.0);
+        let total = self.weight_total.unwrap_or(0.0);
+
+        if total <= 0.0 {
+            // Completed units without any total units (yet) are treated as complete:
+            return Some((completed, 0.0));
+        }
+
+        Some((completed.min(total), completed.max(total)))
+    }
+
+    pub(crate) fn effective_aggregate(&self) -> Aggregate {
+        let (completed, total) = self.effective_discrete();
+        let weighted = self.effective_weighted();
+
+        Aggregate::new(completed, total, weighted)
+    }
 }